@@ -0,0 +1,151 @@
+//! Throughput of [`CircularBuffer`] against the previous implementation, which kept an
+//! `Option<T>` in every slot and told full from empty by looking at the slot under the
+//! write cursor.
+//!
+//! Run with `cargo bench --bench throughput`.
+
+use circular_buffer::CircularBuffer;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// The `Option<T>` slot implementation, as it was before tracking head and length.
+mod baseline {
+    pub struct CircularBuffer<T> {
+        buffer: Vec<Option<T>>,
+        start_cursor: usize,
+        end_cursor: usize,
+    }
+
+    impl<T> CircularBuffer<T> {
+        pub fn new(capacity: usize) -> Self {
+            CircularBuffer {
+                buffer: (0..capacity).map(|_| None).collect(),
+                start_cursor: 0,
+                end_cursor: 0,
+            }
+        }
+
+        pub fn write(&mut self, element: T) -> Result<(), ()> {
+            if self.start_cursor == self.end_cursor && self.buffer[self.end_cursor].is_some() {
+                return Err(());
+            }
+            self.buffer[self.end_cursor] = Some(element);
+            self.end_cursor = self.increase_index(self.end_cursor);
+
+            Ok(())
+        }
+
+        pub fn read(&mut self) -> Result<T, ()> {
+            self.buffer[self.start_cursor]
+                .take()
+                .ok_or(())
+                .inspect(|_| {
+                    self.start_cursor = self.increase_index(self.start_cursor);
+                })
+        }
+
+        pub fn overwrite(&mut self, element: T) {
+            self.buffer[self.end_cursor] = Some(element);
+
+            if self.start_cursor == self.end_cursor {
+                self.start_cursor = self.increase_index(self.start_cursor)
+            }
+            self.end_cursor = self.increase_index(self.end_cursor);
+        }
+
+        fn increase_index(&self, index: usize) -> usize {
+            (index + 1) % self.buffer.len()
+        }
+    }
+}
+
+const CAPACITY: usize = 1024;
+/// Elements pushed through the buffer per iteration.
+const ELEMENTS: usize = 64 * 1024;
+
+fn write_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write_read");
+    group.throughput(Throughput::Elements(ELEMENTS as u64));
+
+    // fill the buffer, then empty it again
+    group.bench_function(BenchmarkId::new("length", CAPACITY), |b| {
+        let mut buffer = CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for round in 0..ELEMENTS / CAPACITY {
+                for i in 0..CAPACITY {
+                    buffer.write(black_box(round + i)).unwrap();
+                }
+                for _ in 0..CAPACITY {
+                    black_box(buffer.read().unwrap());
+                }
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("option", CAPACITY), |b| {
+        let mut buffer = baseline::CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for round in 0..ELEMENTS / CAPACITY {
+                for i in 0..CAPACITY {
+                    buffer.write(black_box(round + i)).unwrap();
+                }
+                for _ in 0..CAPACITY {
+                    black_box(buffer.read().unwrap());
+                }
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn overwrite(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overwrite");
+    group.throughput(Throughput::Elements(ELEMENTS as u64));
+
+    // a full buffer, every write evicts the oldest element
+    group.bench_function(BenchmarkId::new("length", CAPACITY), |b| {
+        let mut buffer = CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for i in 0..ELEMENTS {
+                buffer.overwrite(black_box(i));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("option", CAPACITY), |b| {
+        let mut buffer = baseline::CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for i in 0..ELEMENTS {
+                buffer.overwrite(black_box(i));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn strings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("strings");
+    group.throughput(Throughput::Elements(ELEMENTS as u64));
+
+    // elements that own memory and have to be dropped on eviction
+    group.bench_function(BenchmarkId::new("length", CAPACITY), |b| {
+        let mut buffer = CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for i in 0..ELEMENTS {
+                buffer.overwrite(i.to_string());
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("option", CAPACITY), |b| {
+        let mut buffer = baseline::CircularBuffer::new(CAPACITY);
+        b.iter(|| {
+            for i in 0..ELEMENTS {
+                buffer.overwrite(i.to_string());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, write_read, overwrite, strings);
+criterion_main!(benches);
@@ -0,0 +1,297 @@
+//! A [`CircularBuffer`] with its capacity fixed at compile time and its elements stored
+//! inline, so it needs neither `std` nor an allocator.
+//!
+//! The API matches [`crate::CircularBuffer`]. The tests of the unsafe storage code run
+//! under miri with `cargo +nightly miri test --test array`, after
+//! `rustup +nightly component add miri`.
+
+use core::{fmt, iter::Chain, mem::MaybeUninit, ptr, slice};
+
+use crate::Error;
+
+pub struct CircularBuffer<T, const N: usize> {
+    slots: [MaybeUninit<T>; N],
+    // slot of the oldest element
+    head: usize,
+    // slots from `head` on (wrapping) hold initialized elements
+    len: usize,
+}
+
+impl<T, const N: usize> CircularBuffer<T, N> {
+    pub const fn new() -> Self {
+        CircularBuffer {
+            slots: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn write(&mut self, element: T) -> Result<(), Error> {
+        if self.len == N {
+            return Err(Error::FullBuffer);
+        }
+
+        let slot = self.slot(self.len);
+        self.slots[slot].write(element);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn read(&mut self) -> Result<T, Error> {
+        if self.len == 0 {
+            return Err(Error::EmptyBuffer);
+        }
+
+        // SAFETY: the buffer isn't empty, so the slot at head is initialized; moving the
+        // head past it marks it uninitialized again
+        let element = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = self.slot(1);
+        self.len -= 1;
+
+        Ok(element)
+    }
+
+    /// Drop all elements.
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+
+        // forget the elements first: a panicking drop leaks the rest instead of
+        // dropping elements twice
+        self.head = 0;
+        self.len = 0;
+
+        // SAFETY: the slices held the initialized elements, which are no longer tracked
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    /// Write, evicting the oldest element to make room when the buffer is full. Returns
+    /// the evicted element; without capacity that is `element` itself.
+    pub fn overwrite(&mut self, element: T) -> Option<T> {
+        if N == 0 {
+            return Some(element);
+        }
+
+        let evicted = if self.len == N {
+            self.read().ok()
+        } else {
+            None
+        };
+        // can't fail: there is room now
+        let _ = self.write(element);
+
+        evicted
+    }
+
+    /// The oldest element, the one `read` would return next.
+    pub fn peek(&self) -> Result<&T, Error> {
+        self.iter().next().ok_or(Error::EmptyBuffer)
+    }
+
+    /// The newest element, the one written last.
+    pub fn peek_back(&self) -> Result<&T, Error> {
+        self.iter().next_back().ok_or(Error::EmptyBuffer)
+    }
+
+    /// Iterate over the elements from oldest to newest.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+
+    /// Iterate mutably over the elements from oldest to newest.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back.iter_mut())
+    }
+
+    /// Remove the elements from oldest to newest. Elements not iterated over are
+    /// dropped together with the iterator.
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        Drain { buffer: self }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Slot of the element `offset` places after the oldest one.
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % N
+    }
+
+    /// The elements as the run from head to the end of the storage and the run wrapped
+    /// around to its start.
+    fn as_slices(&self) -> (&[T], &[T]) {
+        let wrapped = (self.head + self.len).saturating_sub(N);
+        let (start, rest) = self.slots.split_at(self.head);
+        let front = &rest[..self.len - wrapped];
+        let back = &start[..wrapped];
+
+        // SAFETY: exactly these slots hold initialized elements
+        unsafe {
+            (
+                &*(front as *const [MaybeUninit<T>] as *const [T]),
+                &*(back as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let wrapped = (self.head + self.len).saturating_sub(N);
+        let (start, rest) = self.slots.split_at_mut(self.head);
+        let front = &mut rest[..self.len - wrapped];
+        let back = &mut start[..wrapped];
+
+        // SAFETY: exactly these slots hold initialized elements
+        unsafe {
+            (
+                &mut *(front as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(back as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Default for CircularBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for CircularBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Lists the elements from oldest to newest.
+impl<T: fmt::Debug, const N: usize> fmt::Debug for CircularBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for CircularBuffer<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = CircularBuffer::new();
+        for element in self {
+            // can't fail: the clone has the same capacity
+            let _ = clone.write(element.clone());
+        }
+        clone
+    }
+}
+
+/// Buffers are equal when they hold equal elements in the same order.
+impl<T: PartialEq, const N: usize> PartialEq for CircularBuffer<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for CircularBuffer<T, N> {}
+
+/// Overwrites, so a full buffer keeps the newest elements.
+impl<T, const N: usize> Extend<T> for CircularBuffer<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.overwrite(element);
+        }
+    }
+}
+
+/// Borrowing iterator over the elements of a [`CircularBuffer`], oldest first.
+pub type Iter<'a, T> = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+/// Mutably borrowing iterator over the elements of a [`CircularBuffer`], oldest first.
+pub type IterMut<'a, T> = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+/// Owning iterator over the elements of a [`CircularBuffer`], oldest first.
+pub struct IntoIter<T, const N: usize> {
+    buffer: CircularBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.read().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+/// Iterator reading the elements out of a [`CircularBuffer`], oldest first.
+///
+/// The buffer is empty once the iterator is dropped, whether or not it was exhausted.
+pub struct Drain<'a, T, const N: usize> {
+    buffer: &'a mut CircularBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.read().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl<T, const N: usize> IntoIterator for CircularBuffer<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { buffer: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a CircularBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut CircularBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+
+use crate::CircularBuffer;
+
+/// Writing and reading many elements in one call.
+impl<T, H> CircularBuffer<T, H> {
+    /// Write all elements, or none of them when they don't all fit.
+    ///
+    /// On overflow the elements already written are taken back out and returned, in
+    /// order and followed by the one that didn't fit; pass the iterator by reference to
+    /// keep the ones after it. In auto-grow mode the buffer grows to take them all.
+    pub fn write_all_or_none<I: IntoIterator<Item = T>>(
+        &mut self,
+        elements: I,
+    ) -> Result<(), Vec<T>> {
+        let elements = elements.into_iter();
+        let (count, _) = elements.size_hint();
+        if self.auto_grow && count > self.capacity() - self.len {
+            self.grow_for(count);
+        }
+
+        let len = self.len;
+        for element in elements {
+            if !self.auto_grow && self.len == self.capacity() {
+                let mut rejected = Vec::with_capacity(self.len - len + 1);
+                while self.len > len {
+                    // can't fail: the buffer holds the elements written above
+                    rejected.extend(self.pop_back());
+                }
+                rejected.reverse();
+                rejected.push(element);
+                return Err(rejected);
+            }
+            // can't fail: there is room, or the buffer grows
+            let _ = self.write(element);
+        }
+
+        Ok(())
+    }
+
+    /// Write elements until the buffer is full. Returns how many were written, and the
+    /// iterator with the elements that weren't.
+    ///
+    /// In auto-grow mode the buffer is never full, so this takes every element: don't
+    /// pass an unbounded iterator.
+    pub fn write_many<I: IntoIterator<Item = T>>(&mut self, elements: I) -> (usize, I::IntoIter) {
+        let mut elements = elements.into_iter();
+        let mut written = 0;
+
+        // only take an element out of the iterator once there is room for it
+        while self.auto_grow || self.len < self.capacity() {
+            let Some(element) = elements.next() else {
+                break;
+            };
+            // can't fail: there is room, or the buffer grows
+            let _ = self.write(element);
+            written += 1;
+        }
+
+        (written, elements)
+    }
+
+    /// Read up to `n` elements, oldest first.
+    pub fn read_many(&mut self, n: usize) -> Vec<T> {
+        let count = n.min(self.len);
+        (0..count).filter_map(|_| self.read().ok()).collect()
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+use futures_sink::Sink;
+
+use crate::CircularBuffer;
+
+/// Why a value could not be sent.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SendError {
+    // no free capacity, wait for it with `Sink::poll_ready`
+    Full,
+    // the receiver was dropped
+    Closed,
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SendError::Full => write!(f, "channel is full"),
+            SendError::Closed => write!(f, "receiver was dropped"),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+struct State<T> {
+    buffer: CircularBuffer<T>,
+    // overwrite the oldest value instead of waiting for capacity
+    lossy: bool,
+    // slots promised to senders by `poll_ready`, not yet filled by `start_send`
+    reserved: usize,
+    senders: usize,
+    receiver_alive: bool,
+    receiver_waker: Option<Waker>,
+    sender_wakers: Vec<Waker>,
+}
+
+impl<T> State<T> {
+    fn wake_receiver(&mut self) {
+        if let Some(waker) = self.receiver_waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Whether a value can be written without taking a slot reserved by someone else.
+    fn has_room(&self) -> bool {
+        self.buffer.len() + self.reserved < self.buffer.capacity()
+    }
+
+    fn wake_senders(&mut self) {
+        for waker in self.sender_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+type Shared<T> = Arc<Mutex<State<T>>>;
+
+fn lock<T>(shared: &Shared<T>) -> MutexGuard<'_, State<T>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Async channel on a [`CircularBuffer`]: senders wait for free capacity, the receiver
+/// waits for values.
+///
+/// # Panics
+///
+/// With a capacity of 0: no sender could ever send.
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "channel without capacity");
+    new_channel(capacity, false)
+}
+
+/// Async channel on a [`CircularBuffer`] that never makes senders wait: when the buffer
+/// is full, the oldest value is overwritten.
+pub fn lossy_channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    new_channel(capacity, true)
+}
+
+fn new_channel<T>(capacity: usize, lossy: bool) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(State {
+        buffer: CircularBuffer::new(capacity),
+        lossy,
+        reserved: 0,
+        senders: 1,
+        receiver_alive: true,
+        receiver_waker: None,
+        sender_wakers: Vec::new(),
+    }));
+
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+            closed: false,
+            reserved: AtomicBool::new(false),
+        },
+        Receiver { shared },
+    )
+}
+
+/// Sending half of a [`channel`], implements [`Sink`]. Clone it for more senders.
+pub struct Sender<T> {
+    shared: Shared<T>,
+    // `poll_close` was called
+    closed: bool,
+    // holds one of the reserved slots
+    reserved: AtomicBool,
+}
+
+impl<T> Sender<T> {
+    /// Send without waiting. In a lossy channel this only fails once the receiver is gone.
+    pub fn try_send(&self, value: T) -> Result<(), SendError> {
+        let mut state = lock(&self.shared);
+        if !state.receiver_alive || self.closed {
+            return Err(SendError::Closed);
+        }
+
+        if self.reserved.swap(false, Ordering::Relaxed) {
+            // the slot reserved by `poll_ready` is free
+            state.reserved -= 1;
+        } else if !state.lossy && !state.has_room() {
+            return Err(SendError::Full);
+        }
+
+        state.buffer.overwrite(value);
+        state.wake_receiver();
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        if !self.closed {
+            self.closed = true;
+            let mut state = lock(&self.shared);
+            if self.reserved.swap(false, Ordering::Relaxed) {
+                state.reserved -= 1;
+                state.wake_senders();
+            }
+            state.senders -= 1;
+            if state.senders == 0 {
+                state.wake_receiver();
+            }
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        lock(&self.shared).senders += 1;
+        Sender {
+            shared: Arc::clone(&self.shared),
+            closed: false,
+            reserved: AtomicBool::new(false),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = SendError;
+
+    /// Wait for free capacity and reserve it for the next `start_send`.
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let mut state = lock(&self.shared);
+        if !state.receiver_alive || self.closed {
+            return Poll::Ready(Err(SendError::Closed));
+        }
+        if state.lossy || self.reserved.load(Ordering::Relaxed) {
+            return Poll::Ready(Ok(()));
+        }
+        if state.has_room() {
+            state.reserved += 1;
+            self.reserved.store(true, Ordering::Relaxed);
+            return Poll::Ready(Ok(()));
+        }
+
+        if !state.sender_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.sender_wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    fn start_send(self: Pin<&mut Self>, value: T) -> Result<(), SendError> {
+        self.try_send(value)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        // sent values are in the buffer right away
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        self.close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Receiving half of a [`channel`], implements [`Stream`].
+///
+/// The stream ends once all senders are dropped or closed and the buffer is empty.
+pub struct Receiver<T> {
+    shared: Shared<T>,
+}
+
+impl<T> Receiver<T> {
+    /// Receive without waiting.
+    pub fn try_recv(&self) -> Result<T, crate::Error> {
+        let mut state = lock(&self.shared);
+        let value = state.buffer.read()?;
+        state.wake_senders();
+        Ok(value)
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = lock(&self.shared);
+        state.receiver_alive = false;
+        state.wake_senders();
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = lock(&self.shared);
+        if let Ok(value) = state.buffer.read() {
+            state.wake_senders();
+            return Poll::Ready(Some(value));
+        }
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        state.receiver_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
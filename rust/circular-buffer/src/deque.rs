@@ -0,0 +1,124 @@
+use core::{
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{CircularBuffer, Error};
+
+/// Random access and deque-style use. Index 0 is the oldest element, the one `read`
+/// returns next, and `len() - 1` the newest.
+impl<T, H> CircularBuffer<T, H> {
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        // SAFETY: the first `len` slots from head on hold initialized elements
+        Some(unsafe { self.slots[self.slot(index)].assume_init_ref() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let slot = self.slot(index);
+        // SAFETY: the first `len` slots from head on hold initialized elements
+        Some(unsafe { self.slots[slot].assume_init_mut() })
+    }
+
+    /// The oldest element.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// The newest element.
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Write in front of the oldest element, so `read` returns it next.
+    pub fn push_front(&mut self, element: T) -> Result<(), Error> {
+        if self.len == self.capacity() {
+            if !self.auto_grow {
+                return Err(Error::FullBuffer);
+            }
+            self.grow_for(1);
+        }
+
+        self.head = self.slot(self.capacity() - 1);
+        self.slots[self.head].write(element);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Read the newest element instead of the oldest.
+    pub fn pop_back(&mut self) -> Result<T, Error> {
+        if self.len == 0 {
+            return Err(Error::EmptyBuffer);
+        }
+
+        self.len -= 1;
+        // SAFETY: the slot was the last initialized one, and no longer counts as such
+        Ok(unsafe { self.slots[self.slot(self.len)].assume_init_read() })
+    }
+
+    /// Move the `n` oldest elements behind the newest one, so the element at index `n`
+    /// becomes the oldest.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is larger than the number of elements.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotating {} of {} elements", n, self.len);
+
+        if self.len == self.capacity() {
+            // no free slots in between: the elements stay where they are
+            if n > 0 {
+                self.head = self.slot(n);
+            }
+            return;
+        }
+
+        for _ in 0..n {
+            // SAFETY: the oldest element moves to the free slot behind the newest one
+            let element = unsafe { self.slots[self.head].assume_init_read() };
+            let tail = self.slot(self.len);
+            self.slots[tail].write(element);
+            self.head = self.slot(1);
+        }
+    }
+
+    /// Move the elements so they are stored in order in one slice, and return it.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > self.capacity() {
+            // moving whole slots moves the elements in them along
+            self.slots.rotate_left(self.head);
+            self.head = 0;
+        }
+
+        let elements = &mut self.slots[self.head..self.head + self.len];
+        // SAFETY: these slots hold the initialized elements, `MaybeUninit<T>` has the
+        // layout of `T`
+        unsafe { slice::from_raw_parts_mut(elements.as_mut_ptr().cast::<T>(), elements.len()) }
+    }
+}
+
+impl<T, H> Index<usize> for CircularBuffer<T, H> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {} out of bounds for {} elements", index, len))
+    }
+}
+
+impl<T, H> IndexMut<usize> for CircularBuffer<T, H> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {} out of bounds for {} elements", index, len))
+    }
+}
@@ -0,0 +1,21 @@
+/// Gets to see every element [`crate::CircularBuffer::overwrite`] evicts, for example
+/// to count dropped samples.
+///
+/// Closures taking `&T` are hooks.
+pub trait EvictionHook<T> {
+    fn evicted(&self, element: &T);
+}
+
+impl<T, F: Fn(&T)> EvictionHook<T> for F {
+    fn evicted(&self, element: &T) {
+        self(element)
+    }
+}
+
+/// The default hook, ignoring evicted elements.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoHook;
+
+impl<T> EvictionHook<T> for NoHook {
+    fn evicted(&self, _element: &T) {}
+}
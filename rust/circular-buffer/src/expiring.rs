@@ -0,0 +1,156 @@
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{CircularBuffer, Error};
+
+/// Source of the current time for an [`ExpiringBuffer`].
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The system's monotonic clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to, for tests.
+///
+/// Clones share the same time, so a test can keep one clone and advance the clone
+/// handed to the buffer.
+#[derive(Debug, Clone)]
+pub struct FakeClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl FakeClock {
+    pub fn new() -> Self {
+        FakeClock {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A [`CircularBuffer`] whose elements expire `ttl` after they were written.
+///
+/// Expired elements are dropped whenever the buffer is accessed, which is why even the
+/// queries take `&mut self`.
+pub struct ExpiringBuffer<T, C = SystemClock> {
+    entries: CircularBuffer<(Instant, T)>,
+    ttl: Duration,
+    clock: C,
+}
+
+impl<T> ExpiringBuffer<T> {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self::with_clock(capacity, ttl, SystemClock)
+    }
+}
+
+impl<T, C: Clock> ExpiringBuffer<T, C> {
+    pub fn with_clock(capacity: usize, ttl: Duration, clock: C) -> Self {
+        ExpiringBuffer {
+            entries: CircularBuffer::new(capacity),
+            ttl,
+            clock,
+        }
+    }
+
+    pub fn write(&mut self, element: T) -> Result<(), Error> {
+        let now = self.clock.now();
+        self.expire_at(now);
+        self.entries.write((now, element))
+    }
+
+    /// Read the oldest element that hasn't expired.
+    pub fn read(&mut self) -> Result<T, Error> {
+        self.expire();
+        self.entries.read().map(|(_, element)| element)
+    }
+
+    /// Write, evicting the oldest element that hasn't expired when the buffer is full.
+    pub fn overwrite(&mut self, element: T) -> Option<T> {
+        let now = self.clock.now();
+        self.expire_at(now);
+        self.entries
+            .overwrite((now, element))
+            .map(|(_, element)| element)
+    }
+
+    /// Drop the expired elements. Returns how many there were.
+    pub fn expire(&mut self) -> usize {
+        let now = self.clock.now();
+        self.expire_at(now)
+    }
+
+    /// How many elements were written less than `window` ago.
+    pub fn count_within(&mut self, window: Duration) -> usize {
+        let now = self.clock.now();
+        self.expire_at(now);
+        self.entries
+            .iter()
+            .rev()
+            .take_while(|&&(written, _)| now.saturating_duration_since(written) < window)
+            .count()
+    }
+
+    /// Iterate over the elements that haven't expired, oldest first.
+    pub fn iter(&mut self) -> impl Iterator<Item = &T> {
+        self.expire();
+        self.entries.iter().map(|(_, element)| element)
+    }
+
+    pub fn len(&mut self) -> usize {
+        self.expire();
+        self.entries.len()
+    }
+
+    pub fn is_empty(&mut self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Drop the elements that are expired at `now`. Elements are written in time order,
+    /// so they expire oldest first.
+    fn expire_at(&mut self, now: Instant) -> usize {
+        let mut expired = 0;
+        while self
+            .entries
+            .front()
+            .is_some_and(|&(written, _)| now.saturating_duration_since(written) >= self.ttl)
+        {
+            let _ = self.entries.read();
+            expired += 1;
+        }
+        expired
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+use core::{fmt, mem::MaybeUninit};
+
+use crate::{CircularBuffer, EvictionHook};
+
+/// Lists the elements from oldest to newest.
+impl<T: fmt::Debug, H> fmt::Debug for CircularBuffer<T, H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Clones the elements into a buffer with the same capacity and settings, and a clone
+/// of the eviction hook.
+impl<T: Clone, H: Clone> Clone for CircularBuffer<T, H> {
+    fn clone(&self) -> Self {
+        let mut clone = CircularBuffer {
+            slots: (0..self.capacity())
+                .map(|_| MaybeUninit::uninit())
+                .collect(),
+            head: 0,
+            len: 0,
+            eviction_hook: self.eviction_hook.clone(),
+            shrink_policy: self.shrink_policy,
+            auto_grow: self.auto_grow,
+        };
+        for element in self {
+            // can't fail: the clone has the same capacity
+            let _ = clone.write(element.clone());
+        }
+        clone
+    }
+}
+
+/// Buffers are equal when they hold equal elements in the same order, whatever their
+/// capacity, eviction hook and wherever the elements are stored.
+impl<T: PartialEq, H, G> PartialEq<CircularBuffer<T, G>> for CircularBuffer<T, H> {
+    fn eq(&self, other: &CircularBuffer<T, G>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, H> Eq for CircularBuffer<T, H> {}
+
+/// Collects into a full buffer, exactly as large as the number of elements.
+impl<T> FromIterator<T> for CircularBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elements = iter.into_iter().collect::<Vec<_>>();
+        let mut buffer = CircularBuffer::new(elements.len());
+        for element in elements {
+            // can't fail: there is a slot for every element
+            let _ = buffer.write(element);
+        }
+        buffer
+    }
+}
+
+/// Overwrites, so a full buffer keeps the newest elements.
+impl<T, H: EvictionHook<T>> Extend<T> for CircularBuffer<T, H> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.overwrite(element);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Byte buffers as a staging area between readers and writers.
+//!
+//! Reading an empty buffer returns 0 bytes, which [`io::Read`] users take as the end of
+//! the stream; writing a full buffer accepts 0 bytes, which makes [`io::Write::write_all`]
+//! fail with [`io::ErrorKind::WriteZero`].
+//!
+//! The inherent `read` and `write` of a single byte shadow the trait methods, call those
+//! as `io::Write::write(&mut buffer, bytes)` or use `read_exact`, `write_all` and friends.
+
+use std::io;
+
+use crate::CircularBuffer;
+
+impl<H> io::Read for CircularBuffer<u8, H> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(self.read_into(buf))
+    }
+}
+
+impl<H> io::BufRead for CircularBuffer<u8, H> {
+    /// The oldest contiguous run of bytes, possibly only part of the buffer when it wraps.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.as_slices().0)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.skip(amt);
+    }
+}
+
+impl<H> io::Write for CircularBuffer<u8, H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.write_slice(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
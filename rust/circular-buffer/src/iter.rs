@@ -0,0 +1,154 @@
+use core::{iter::Chain, slice};
+
+use crate::{CircularBuffer, NoHook};
+
+/// Borrowing iterator over the elements of a [`CircularBuffer`], oldest first.
+pub struct Iter<'a, T> {
+    // elements from the head to the end of the storage, then from its start
+    elements: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(
+        elements: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+        remaining: usize,
+    ) -> Self {
+        Iter {
+            elements,
+            remaining,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.elements.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutably borrowing iterator over the elements of a [`CircularBuffer`], oldest first.
+pub struct IterMut<'a, T> {
+    elements: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+    remaining: usize,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(
+        elements: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+        remaining: usize,
+    ) -> Self {
+        IterMut {
+            elements,
+            remaining,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.elements.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Owning iterator over the elements of a [`CircularBuffer`], oldest first.
+pub struct IntoIter<T, H = NoHook> {
+    buffer: CircularBuffer<T, H>,
+}
+
+impl<T, H> IntoIter<T, H> {
+    pub(crate) fn new(buffer: CircularBuffer<T, H>) -> Self {
+        IntoIter { buffer }
+    }
+}
+
+impl<T, H> Iterator for IntoIter<T, H> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.read().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, H> ExactSizeIterator for IntoIter<T, H> {}
+
+/// Iterator reading the elements out of a [`CircularBuffer`], oldest first.
+///
+/// The buffer is empty once the iterator is dropped, whether or not it was exhausted.
+pub struct Drain<'a, T, H = NoHook> {
+    buffer: &'a mut CircularBuffer<T, H>,
+}
+
+impl<'a, T, H> Drain<'a, T, H> {
+    pub(crate) fn new(buffer: &'a mut CircularBuffer<T, H>) -> Self {
+        Drain { buffer }
+    }
+}
+
+impl<T, H> Iterator for Drain<'_, T, H> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.read().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len(), Some(self.buffer.len()))
+    }
+}
+
+impl<T, H> ExactSizeIterator for Drain<'_, T, H> {}
+
+impl<T, H> Drop for Drain<'_, T, H> {
+    fn drop(&mut self) {
+        self.buffer.clear();
+    }
+}
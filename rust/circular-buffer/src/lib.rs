@@ -1,7 +1,80 @@
-pub struct CircularBuffer<T> {
-    buffer: Vec<Option<T>>,
-    start_cursor: usize,
-    end_cursor: usize,
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+pub mod array;
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "async")]
+pub mod channel;
+#[cfg(feature = "alloc")]
+mod deque;
+#[cfg(feature = "alloc")]
+mod eviction;
+#[cfg(feature = "std")]
+mod expiring;
+#[cfg(feature = "alloc")]
+mod impls;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod iter;
+#[cfg(feature = "std")]
+mod mpmc;
+#[cfg(feature = "alloc")]
+mod resize;
+#[cfg(feature = "std")]
+mod ring_log;
+#[cfg(feature = "alloc")]
+mod rolling;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "alloc")]
+mod spsc;
+#[cfg(feature = "alloc")]
+mod sync;
+
+#[cfg(feature = "alloc")]
+pub use eviction::{EvictionHook, NoHook};
+#[cfg(feature = "std")]
+pub use expiring::{Clock, ExpiringBuffer, FakeClock, SystemClock};
+#[cfg(feature = "alloc")]
+pub use iter::{Drain, IntoIter, Iter, IterMut};
+#[cfg(feature = "std")]
+pub use mpmc::BoundedQueue;
+#[cfg(feature = "alloc")]
+pub use resize::ShrinkPolicy;
+#[cfg(feature = "std")]
+pub use ring_log::{LogError, RingLog};
+#[cfg(feature = "alloc")]
+pub use rolling::RollingWindow;
+#[cfg(feature = "alloc")]
+pub use spsc::{Consumer, Producer};
+
+/// A ring of `capacity` elements, read in the order they were written.
+///
+/// `H` is the [`EvictionHook`] told about the elements `overwrite` evicts; the default
+/// [`NoHook`] ignores them.
+#[cfg(feature = "alloc")]
+pub struct CircularBuffer<T, H = NoHook> {
+    slots: Box<[MaybeUninit<T>]>,
+    // slot of the oldest element
+    head: usize,
+    // slots from `head` on (wrapping) hold initialized elements
+    len: usize,
+    eviction_hook: H,
+    shrink_policy: ShrinkPolicy,
+    // reallocate instead of rejecting writes to a full buffer
+    auto_grow: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -10,64 +83,325 @@ pub enum Error {
     FullBuffer,
 }
 
+#[cfg(feature = "alloc")]
 impl<T> CircularBuffer<T> {
     pub fn new(capacity: usize) -> Self {
         CircularBuffer {
-            buffer: (0..capacity).map(|_| None).collect(),
-            start_cursor: 0,
-            end_cursor: 0,
+            slots: (0..capacity).map(|_| MaybeUninit::uninit()).collect(),
+            head: 0,
+            len: 0,
+            eviction_hook: NoHook,
+            shrink_policy: ShrinkPolicy::default(),
+            auto_grow: false,
         }
     }
 
+    /// Like `new`, but `None` instead of aborting when the storage can't be allocated.
+    #[cfg(feature = "serde")]
+    pub(crate) fn try_new(capacity: usize) -> Option<Self> {
+        let mut slots = alloc::vec::Vec::new();
+        slots.try_reserve_exact(capacity).ok()?;
+        // SAFETY: the capacity is reserved, and uninitialized slots are valid `MaybeUninit`s
+        unsafe { slots.set_len(capacity) };
+
+        let mut buffer = CircularBuffer::new(0);
+        buffer.slots = slots.into_boxed_slice();
+        Some(buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, H> CircularBuffer<T, H> {
     pub fn write(&mut self, element: T) -> Result<(), Error> {
-        if self.start_cursor == self.end_cursor {
-            if self.buffer[self.end_cursor].is_none() {
-                self.buffer[self.end_cursor] = Some(element);
-                self.end_cursor = self.increase_index(self.end_cursor);
-            } else {
+        if self.len == self.capacity() {
+            if !self.auto_grow {
                 return Err(Error::FullBuffer);
             }
-        } else {
-            self.buffer[self.end_cursor] = Some(element);
-            self.end_cursor = self.increase_index(self.end_cursor);
+            self.grow_for(1);
         }
 
+        let slot = self.slot(self.len);
+        self.slots[slot].write(element);
+        self.len += 1;
+
         Ok(())
     }
 
     pub fn read(&mut self) -> Result<T, Error> {
-        self.buffer[self.start_cursor]
-            .take()
-            .ok_or(Error::EmptyBuffer)
-            .map(|value| {
-                self.start_cursor = self.increase_index(self.start_cursor);
-                value
-            })
+        if self.len == 0 {
+            return Err(Error::EmptyBuffer);
+        }
+
+        // SAFETY: the buffer isn't empty, so the slot at head is initialized; moving the
+        // head past it marks it uninitialized again
+        let element = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = self.slot(1);
+        self.len -= 1;
+
+        Ok(element)
     }
 
+    /// Drop all elements.
     pub fn clear(&mut self) {
-        if self.start_cursor == self.end_cursor {
-            if self.buffer[self.end_cursor].is_some() {
-                self.buffer[self.start_cursor] = None;
-                self.start_cursor = self.increase_index(self.start_cursor);
-                self.end_cursor = self.increase_index(self.end_cursor);
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+
+        // forget the elements first: a panicking drop leaks the rest instead of
+        // dropping elements twice
+        self.head = 0;
+        self.len = 0;
+
+        // SAFETY: the slices held the initialized elements, which are no longer tracked
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    /// Keep the `len` oldest elements and drop the newer ones.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            drop(self.pop_back());
+        }
+    }
+
+    /// Keep only the elements `keep` returns true for, in their order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        for _ in 0..self.len {
+            // cycle every element through the buffer once: reading one frees the slot
+            // writing it back needs
+            if let Ok(element) = self.read() {
+                if keep(&element) {
+                    let _ = self.write(element);
+                }
             }
+        }
+    }
+
+    /// Report every element `overwrite` evicts to `hook`, replacing any previous hook.
+    pub fn with_eviction_hook<G: EvictionHook<T>>(self, hook: G) -> CircularBuffer<T, G> {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: every field is moved out exactly once, and `this` is never dropped
+        unsafe {
+            drop(ptr::read(&this.eviction_hook));
+            CircularBuffer {
+                slots: ptr::read(&this.slots),
+                head: this.head,
+                len: this.len,
+                eviction_hook: hook,
+                shrink_policy: this.shrink_policy,
+                auto_grow: this.auto_grow,
+            }
+        }
+    }
+
+    /// The oldest element, the one `read` would return next.
+    pub fn peek(&self) -> Result<&T, Error> {
+        self.iter().next().ok_or(Error::EmptyBuffer)
+    }
+
+    /// The newest element, the one written last.
+    pub fn peek_back(&self) -> Result<&T, Error> {
+        let (front, back) = self.as_slices();
+        back.last().or(front.last()).ok_or(Error::EmptyBuffer)
+    }
+
+    /// Iterate over the elements from oldest to newest.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front.iter().chain(back.iter()), self.len)
+    }
+
+    /// Iterate mutably over the elements from oldest to newest.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.len;
+        let (front, back) = self.as_mut_slices();
+        IterMut::new(front.iter_mut().chain(back.iter_mut()), len)
+    }
+
+    /// Remove the elements from oldest to newest. Elements not iterated over are
+    /// dropped together with the iterator.
+    pub fn drain(&mut self) -> Drain<'_, T, H> {
+        Drain::new(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// The elements, oldest first, as two contiguous halves: the run up to the end of
+    /// the storage and the run wrapped around to its start. The second half is empty
+    /// when the elements don't wrap.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let wrapped = (self.head + self.len).saturating_sub(self.capacity());
+        let (start, rest) = self.slots.split_at(self.head);
+        let front = &rest[..self.len - wrapped];
+        let back = &start[..wrapped];
+
+        // SAFETY: exactly these slots hold initialized elements
+        unsafe {
+            (
+                &*(front as *const [MaybeUninit<T>] as *const [T]),
+                &*(back as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// Like [`CircularBuffer::as_slices`], mutably.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let wrapped = (self.head + self.len).saturating_sub(self.capacity());
+        let (start, rest) = self.slots.split_at_mut(self.head);
+        let front = &mut rest[..self.len - wrapped];
+        let back = &mut start[..wrapped];
+
+        // SAFETY: exactly these slots hold initialized elements
+        unsafe {
+            (
+                &mut *(front as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(back as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Slot of the element `offset` places after the oldest one.
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % self.capacity()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, H: EvictionHook<T>> CircularBuffer<T, H> {
+    /// Write, evicting the oldest element to make room when the buffer is full. The
+    /// evicted element is passed to the eviction hook and returned; without
+    /// capacity that is `element` itself.
+    ///
+    /// Overwriting never grows the buffer, not even in auto-grow mode.
+    pub fn overwrite(&mut self, element: T) -> Option<T> {
+        if self.capacity() == 0 {
+            self.eviction_hook.evicted(&element);
+            return Some(element);
+        }
+
+        let evicted = if self.len == self.capacity() {
+            self.evict()
         } else {
-            self.buffer[self.start_cursor] = None;
-            self.start_cursor = self.increase_index(self.start_cursor);
+            None
+        };
+        self.slots[self.slot(self.len)].write(element);
+        self.len += 1;
+
+        evicted
+    }
+
+    /// Read the oldest element and report it to the eviction hook.
+    fn evict(&mut self) -> Option<T> {
+        let evicted = self.read().ok()?;
+        self.eviction_hook.evicted(&evicted);
+        Some(evicted)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy, H> CircularBuffer<T, H> {
+    /// Copy as many elements from `data` as fit, in at most two copies. Returns how
+    /// many were written.
+    ///
+    /// In auto-grow mode the buffer grows to take all of `data`.
+    pub fn write_slice(&mut self, data: &[T]) -> usize {
+        if self.auto_grow && data.len() > self.capacity() - self.len {
+            self.grow_for(data.len());
         }
+
+        let count = data.len().min(self.capacity() - self.len);
+        if count == 0 {
+            return 0;
+        }
+
+        let tail = self.slot(self.len);
+        let (front, back) = data[..count].split_at(count.min(self.capacity() - tail));
+
+        // SAFETY: `MaybeUninit<T>` has the layout of `T`, and the `count` slots from the
+        // tail on (wrapping) are free
+        unsafe {
+            let slots = self.slots.as_mut_ptr().cast::<T>();
+            ptr::copy_nonoverlapping(front.as_ptr(), slots.add(tail), front.len());
+            ptr::copy_nonoverlapping(back.as_ptr(), slots, back.len());
+        }
+        self.len += count;
+
+        count
     }
 
-    pub fn overwrite(&mut self, element: T) {
-        self.buffer[self.end_cursor] = Some(element);
+    /// Read as many elements as fit into `out`, oldest first, in at most two copies.
+    /// Returns how many were read.
+    pub fn read_into(&mut self, out: &mut [T]) -> usize {
+        let (front, back) = self.as_slices();
+        let count = out.len().min(self.len);
+        let first = count.min(front.len());
 
-        if self.start_cursor == self.end_cursor {
-            self.start_cursor = self.increase_index(self.start_cursor)
+        out[..first].copy_from_slice(&front[..first]);
+        out[first..count].copy_from_slice(&back[..count - first]);
+        self.skip(count);
+
+        count
+    }
+
+    /// Forget the `count` oldest elements, which don't need to be dropped.
+    fn skip(&mut self, count: usize) {
+        let count = count.min(self.len);
+        if count > 0 {
+            self.head = self.slot(count);
+            self.len -= count;
         }
-        self.end_cursor = self.increase_index(self.end_cursor);
     }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, H> Drop for CircularBuffer<T, H> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, H> IntoIterator for CircularBuffer<T, H> {
+    type Item = T;
+    type IntoIter = IntoIter<T, H>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, H> IntoIterator for &'a CircularBuffer<T, H> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, H> IntoIterator for &'a mut CircularBuffer<T, H> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
-    fn increase_index(&self, index: usize) -> usize {
-        (index + 1) % self.buffer.len()
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
@@ -0,0 +1,155 @@
+use std::{
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{CircularBuffer, Error};
+
+/// A fixed-capacity [`CircularBuffer`] shared by any number of writers and readers.
+///
+/// Share it between threads with an `Arc`. The `try_` operations never wait, the
+/// `_blocking` ones wait for free capacity or data, and the `_timeout` ones wait at most
+/// the given time before giving up with the `Error` the `try_` variant would return.
+pub struct BoundedQueue<T> {
+    buffer: Mutex<CircularBuffer<T>>,
+    not_full: Condvar,
+    not_empty: Condvar,
+}
+
+impl<T> BoundedQueue<T> {
+    /// # Panics
+    ///
+    /// With a capacity of 0: no writer could ever write.
+    pub fn new(capacity: usize) -> Self {
+        CircularBuffer::new(capacity).into()
+    }
+
+    pub fn try_write(&self, element: T) -> Result<(), Error> {
+        let result = self.lock().write(element);
+        if result.is_ok() {
+            self.not_empty.notify_one();
+        }
+        result
+    }
+
+    pub fn try_read(&self) -> Result<T, Error> {
+        let result = self.lock().read();
+        if result.is_ok() {
+            self.not_full.notify_one();
+        }
+        result
+    }
+
+    /// Write, waiting as long as the queue is full.
+    pub fn write_blocking(&self, element: T) {
+        let mut buffer = self
+            .not_full
+            .wait_while(self.lock(), |buffer| buffer.is_full())
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // can't fail: the buffer is locked and not full
+        let _ = buffer.write(element);
+        drop(buffer);
+        self.not_empty.notify_one();
+    }
+
+    /// Read, waiting as long as the queue is empty.
+    pub fn read_blocking(&self) -> T {
+        let mut buffer = self.lock();
+        loop {
+            match buffer.read() {
+                Ok(element) => {
+                    drop(buffer);
+                    self.not_full.notify_one();
+                    return element;
+                }
+                Err(_) => {
+                    buffer = self
+                        .not_empty
+                        .wait(buffer)
+                        .unwrap_or_else(PoisonError::into_inner)
+                }
+            }
+        }
+    }
+
+    /// Write, waiting at most `timeout` for free capacity.
+    pub fn write_timeout(&self, element: T, timeout: Duration) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+        let mut buffer = self.lock();
+        while buffer.is_full() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::FullBuffer);
+            }
+            buffer = self
+                .not_full
+                .wait_timeout(buffer, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+
+        let result = buffer.write(element);
+        drop(buffer);
+        self.not_empty.notify_one();
+        result
+    }
+
+    /// Read, waiting at most `timeout` for data.
+    pub fn read_timeout(&self, timeout: Duration) -> Result<T, Error> {
+        let deadline = Instant::now() + timeout;
+        let mut buffer = self.lock();
+        while buffer.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::EmptyBuffer);
+            }
+            buffer = self
+                .not_empty
+                .wait_timeout(buffer, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+
+        let result = buffer.read();
+        drop(buffer);
+        self.not_full.notify_one();
+        result
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.lock().is_full()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity()
+    }
+
+    /// The buffer stays consistent when a thread panics while holding the lock:
+    /// every operation on it either completes or leaves it untouched.
+    fn lock(&self) -> MutexGuard<'_, CircularBuffer<T>> {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// # Panics
+///
+/// With a capacity of 0, like [`BoundedQueue::new`].
+impl<T> From<CircularBuffer<T>> for BoundedQueue<T> {
+    fn from(buffer: CircularBuffer<T>) -> Self {
+        assert!(buffer.capacity() > 0, "queue without capacity");
+        BoundedQueue {
+            buffer: Mutex::new(buffer),
+            not_full: Condvar::new(),
+            not_empty: Condvar::new(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use alloc::boxed::Box;
+use core::{mem::MaybeUninit, ptr};
+
+use crate::{CircularBuffer, Error, EvictionHook};
+
+/// What [`CircularBuffer::resize`] does when the new capacity can't hold all elements.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ShrinkPolicy {
+    // keep the buffer as it is and return `Error::FullBuffer`
+    #[default]
+    Reject,
+    // evict the oldest elements that don't fit, reporting them to the eviction hook
+    EvictOldest,
+}
+
+impl<T, H> CircularBuffer<T, H> {
+    /// Decide what [`CircularBuffer::resize`] does with elements that don't fit.
+    pub fn with_shrink_policy(mut self, policy: ShrinkPolicy) -> Self {
+        self.shrink_policy = policy;
+        self
+    }
+
+    /// Reallocate a full buffer on `write` instead of returning `Error::FullBuffer`,
+    /// doubling its capacity.
+    pub fn with_auto_grow(mut self) -> Self {
+        self.auto_grow = true;
+        self
+    }
+
+    /// Make room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len + additional;
+        if needed > self.capacity() {
+            self.relocate(needed);
+        }
+    }
+
+    /// Lower the capacity to `min_capacity`, but never below the number of elements.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let capacity = min_capacity.max(self.len);
+        if capacity < self.capacity() {
+            self.relocate(capacity);
+        }
+    }
+
+    /// Grow in auto-grow mode, at least doubling to keep writes amortized O(1).
+    pub(crate) fn grow_for(&mut self, additional: usize) {
+        let capacity = (self.len + additional).max(self.capacity() * 2);
+        self.relocate(capacity);
+    }
+
+    /// Move the elements, oldest first, to the start of new storage of `capacity` slots.
+    fn relocate(&mut self, capacity: usize) {
+        debug_assert!(capacity >= self.len);
+
+        let mut slots: Box<[MaybeUninit<T>]> =
+            (0..capacity).map(|_| MaybeUninit::uninit()).collect();
+        let (front, back) = self.as_slices();
+
+        // SAFETY: the new storage is large enough and `MaybeUninit<T>` has the layout of
+        // `T`; the old storage only holds `MaybeUninit`s, so the moved elements aren't
+        // dropped with it
+        unsafe {
+            let new = slots.as_mut_ptr().cast::<T>();
+            ptr::copy_nonoverlapping(front.as_ptr(), new, front.len());
+            ptr::copy_nonoverlapping(back.as_ptr(), new.add(front.len()), back.len());
+        }
+
+        self.slots = slots;
+        self.head = 0;
+    }
+}
+
+impl<T, H: EvictionHook<T>> CircularBuffer<T, H> {
+    /// Change the capacity to exactly `new_capacity`, keeping the elements in order.
+    ///
+    /// Shrinking below the number of elements follows the [`ShrinkPolicy`].
+    pub fn resize(&mut self, new_capacity: usize) -> Result<(), Error> {
+        if new_capacity < self.len {
+            match self.shrink_policy {
+                ShrinkPolicy::Reject => return Err(Error::FullBuffer),
+                ShrinkPolicy::EvictOldest => {
+                    while self.len > new_capacity {
+                        self.evict();
+                    }
+                }
+            }
+        }
+
+        if new_capacity != self.capacity() {
+            self.relocate(new_capacity);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,375 @@
+//! A crash-safe ring of the last records, kept in a file of fixed size.
+//!
+//! The file starts with a header naming the layout, followed by one slot per record:
+//!
+//! ```text
+//! file:   magic (8 bytes) | capacity: u32 | max record length: u32 | slot...
+//! slot:   sequence: u64 | length: u32 | CRC-32: u32 | record, padded to the max length
+//! ```
+//!
+//! All integers are little-endian, and the checksum covers sequence, length and record.
+//! Records are written to consecutive slots with consecutive sequence numbers; reading
+//! a record zeroes its slot header. After a restart the cursors are recovered from the
+//! longest run of valid slots ending at the highest sequence number, so a record torn by
+//! a crash is lost but never returned. A record found corrupted when reading it is
+//! skipped and counted, and so are the records older than a gap found on recovery.
+//!
+//! A new log gets its header written and synced before the slots are allocated, and a
+//! file whose header is still all zeros is taken for a new log.
+
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use crate::Error;
+
+const MAGIC: &[u8; 8] = b"CBRLOG01";
+const FILE_HEADER_LEN: u64 = 16;
+const SLOT_HEADER_LEN: usize = 16;
+
+#[derive(Debug)]
+pub enum LogError {
+    // the same conditions as for an in-memory buffer
+    Buffer(Error),
+    // reading or writing the file failed, or it holds a different layout
+    Io(io::Error),
+    // the record doesn't fit a slot
+    RecordTooLong { len: usize, max_record_len: usize },
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LogError::Buffer(Error::EmptyBuffer) => write!(f, "log is empty"),
+            LogError::Buffer(Error::FullBuffer) => write!(f, "log is full"),
+            LogError::Io(err) => write!(f, "log file: {}", err),
+            LogError::RecordTooLong {
+                len,
+                max_record_len,
+            } => write!(f, "record of {} bytes, at most {} fit", len, max_record_len),
+        }
+    }
+}
+
+impl std::error::Error for LogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LogError::Buffer(_) | LogError::RecordTooLong { .. } => None,
+            LogError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<Error> for LogError {
+    fn from(err: Error) -> Self {
+        LogError::Buffer(err)
+    }
+}
+
+impl From<io::Error> for LogError {
+    fn from(err: io::Error) -> Self {
+        LogError::Io(err)
+    }
+}
+
+/// A bounded log of byte records backed by a file, with the API of a
+/// [`crate::CircularBuffer`]. Every change is synced to disk before it returns.
+pub struct RingLog {
+    file: File,
+    capacity: usize,
+    max_record_len: usize,
+    // slot of the oldest record
+    head: usize,
+    len: usize,
+    // sequence number of the next record written
+    next_sequence: u64,
+    // records skipped because their slot was corrupted on disk
+    corrupted: usize,
+}
+
+impl RingLog {
+    /// Open the log at `path`, creating it for `capacity` records of at most
+    /// `max_record_len` bytes if it doesn't exist yet.
+    ///
+    /// An existing log has to have been created with the same layout.
+    pub fn open(
+        path: impl AsRef<Path>,
+        capacity: usize,
+        max_record_len: usize,
+    ) -> Result<Self, LogError> {
+        let invalid =
+            |message: &str| LogError::Io(io::Error::new(io::ErrorKind::InvalidData, message));
+        let (capacity32, max_record_len32) =
+            match (u32::try_from(capacity), u32::try_from(max_record_len)) {
+                (Ok(capacity), Ok(max_record_len)) => (capacity, max_record_len),
+                _ => {
+                    return Err(LogError::Io(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "log layout too large",
+                    )))
+                }
+            };
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        let mut header = [0; FILE_HEADER_LEN as usize];
+        header[..8].copy_from_slice(MAGIC);
+        header[8..12].copy_from_slice(&capacity32.to_le_bytes());
+        header[12..].copy_from_slice(&max_record_len32.to_le_bytes());
+
+        let mut log = RingLog {
+            file,
+            capacity,
+            max_record_len,
+            head: 0,
+            len: 0,
+            next_sequence: 0,
+            corrupted: 0,
+        };
+        let file_len = FILE_HEADER_LEN + (capacity * log.slot_len()) as u64;
+
+        // a file shorter than the header is new, or its header never made it to disk
+        let mut existing = [0; FILE_HEADER_LEN as usize];
+        if log.file.metadata()?.len() >= FILE_HEADER_LEN {
+            log.file.read_exact(&mut existing)?;
+        }
+
+        if existing == [0; FILE_HEADER_LEN as usize] {
+            // the header goes first: a crash before it is synced leaves a file that
+            // still counts as new, whatever its length
+            log.file.seek(SeekFrom::Start(0))?;
+            log.file.write_all(&header)?;
+            log.file.sync_all()?;
+            log.file.set_len(file_len)?;
+            log.file.sync_all()?;
+            return Ok(log);
+        }
+
+        if existing[..8] != MAGIC[..] {
+            return Err(invalid("not a ring log"));
+        }
+        if existing != header {
+            return Err(invalid("ring log created with a different layout"));
+        }
+        if log.file.metadata()?.len() < file_len {
+            // the crash hit between writing the header and allocating the slots, the
+            // missing ones read as zeros, that is empty
+            log.file.set_len(file_len)?;
+            log.file.sync_all()?;
+        }
+
+        log.recover()?;
+        Ok(log)
+    }
+
+    pub fn write(&mut self, record: &[u8]) -> Result<(), LogError> {
+        if self.len == self.capacity {
+            return Err(Error::FullBuffer.into());
+        }
+
+        self.write_slot((self.head + self.len) % self.capacity, record)?;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Read the oldest record, skipping the ones corrupted on disk.
+    pub fn read(&mut self) -> Result<Vec<u8>, LogError> {
+        while self.len > 0 {
+            let record = self.read_slot(self.head)?;
+
+            self.seek_slot(self.head)?;
+            self.file.write_all(&[0; SLOT_HEADER_LEN])?;
+            self.file.sync_data()?;
+            self.head = (self.head + 1) % self.capacity;
+            self.len -= 1;
+
+            match record {
+                Some((_, record)) => return Ok(record),
+                None => self.corrupted += 1,
+            }
+        }
+
+        Err(Error::EmptyBuffer.into())
+    }
+
+    /// Write, evicting the oldest record to make room when the log is full. No record
+    /// is returned when the evicted one was corrupted on disk.
+    pub fn overwrite(&mut self, record: &[u8]) -> Result<Option<Vec<u8>>, LogError> {
+        if self.capacity == 0 {
+            return Err(Error::FullBuffer.into());
+        }
+        if self.len < self.capacity {
+            self.write(record)?;
+            return Ok(None);
+        }
+
+        let evicted = self.read_slot(self.head)?.map(|(_, record)| record);
+        // the new record replaces the oldest one in place, in a single write
+        self.write_slot(self.head, record)?;
+        if evicted.is_none() {
+            self.corrupted += 1;
+        }
+        self.head = (self.head + 1) % self.capacity;
+
+        Ok(evicted)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn max_record_len(&self) -> usize {
+        self.max_record_len
+    }
+
+    /// How many records were dropped since the log was opened, because they or a newer
+    /// record were corrupted on disk.
+    pub fn corrupted(&self) -> usize {
+        self.corrupted
+    }
+
+    fn slot_len(&self) -> usize {
+        SLOT_HEADER_LEN + self.max_record_len
+    }
+
+    fn seek_slot(&mut self, slot: usize) -> io::Result<()> {
+        let offset = FILE_HEADER_LEN + (slot * self.slot_len()) as u64;
+        self.file.seek(SeekFrom::Start(offset)).map(|_| ())
+    }
+
+    fn write_slot(&mut self, slot: usize, record: &[u8]) -> Result<(), LogError> {
+        if record.len() > self.max_record_len {
+            return Err(LogError::RecordTooLong {
+                len: record.len(),
+                max_record_len: self.max_record_len,
+            });
+        }
+
+        let sequence = self.next_sequence.to_le_bytes();
+        let len = (record.len() as u32).to_le_bytes();
+        let mut data = Vec::with_capacity(SLOT_HEADER_LEN + record.len());
+        data.extend_from_slice(&sequence);
+        data.extend_from_slice(&len);
+        data.extend_from_slice(&checksum(&sequence, &len, record).to_le_bytes());
+        data.extend_from_slice(record);
+
+        self.seek_slot(slot)?;
+        self.file.write_all(&data)?;
+        self.file.sync_data()?;
+        self.next_sequence += 1;
+
+        Ok(())
+    }
+
+    /// The sequence number and record in the slot, `None` if it doesn't hold a valid one.
+    fn read_slot(&mut self, slot: usize) -> io::Result<Option<(u64, Vec<u8>)>> {
+        let mut data = vec![0; self.slot_len()];
+        self.seek_slot(slot)?;
+        self.file.read_exact(&mut data)?;
+
+        let (header, payload) = data.split_at(SLOT_HEADER_LEN);
+        let (sequence, rest) = header.split_at(8);
+        let (len, crc) = rest.split_at(4);
+        let record_len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        if record_len > self.max_record_len {
+            return Ok(None);
+        }
+
+        let record = &payload[..record_len];
+        if checksum(sequence, len, record) != u32::from_le_bytes(crc.try_into().unwrap()) {
+            return Ok(None);
+        }
+
+        Ok(Some((
+            u64::from_le_bytes(sequence.try_into().unwrap()),
+            record.to_vec(),
+        )))
+    }
+
+    /// Find the cursors again by scanning every slot.
+    fn recover(&mut self) -> io::Result<()> {
+        let mut sequences = Vec::with_capacity(self.capacity);
+        for slot in 0..self.capacity {
+            sequences.push(self.read_slot(slot)?.map(|(sequence, _)| sequence));
+        }
+
+        let Some((newest, last)) = sequences
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, sequence)| sequence.map(|s| (slot, s)))
+            .max_by_key(|&(_, sequence)| sequence)
+        else {
+            return Ok(());
+        };
+
+        // walk back over the slots holding the preceding sequence numbers
+        let mut len = 1;
+        while len < self.capacity {
+            let slot = (newest + self.capacity - len) % self.capacity;
+            let expected = last.checked_sub(len as u64);
+            if expected.is_none() || sequences[slot] != expected {
+                break;
+            }
+            len += 1;
+        }
+
+        self.head = (newest + self.capacity + 1 - len) % self.capacity;
+        self.len = len;
+        self.next_sequence = last + 1;
+
+        // valid records before a gap are older than the run: drop them for good, or a
+        // later recovery would return them after the newer ones
+        let mut dropped = false;
+        for offset in len..self.capacity {
+            let slot = (self.head + offset) % self.capacity;
+            if sequences[slot].is_some() {
+                self.seek_slot(slot)?;
+                self.file.write_all(&[0; SLOT_HEADER_LEN])?;
+                self.corrupted += 1;
+                dropped = true;
+            }
+        }
+        if dropped {
+            self.file.sync_data()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// CRC-32 (IEEE) of the slot header fields and the record.
+fn checksum(sequence: &[u8], len: &[u8], record: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in sequence.iter().chain(len).chain(record) {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,181 @@
+use crate::CircularBuffer;
+
+/// How much larger than the values left in the window an evicted value may be before
+/// the statistics are recomputed from them: subtracting it back out loses their digits.
+const RECOMPUTE_RATIO: f64 = 16.0;
+
+/// The last `capacity` values pushed, with their sum, mean, variance, minimum and
+/// maximum kept up to date in O(1) amortized per push. When a value much larger than
+/// the rest leaves the window, the statistics are recomputed from the values left.
+///
+/// The statistics are computed in `f64`. Minimum and maximum ignore values that don't
+/// compare, such as NaN.
+pub struct RollingWindow<T> {
+    values: CircularBuffer<T>,
+    // values ever pushed, numbering them for the monotonic deques
+    pushed: u64,
+    sum: f64,
+    // Welford's running mean and sum of squared differences from it
+    mean: f64,
+    m2: f64,
+    // candidates for the minimum, increasing from the oldest to the newest
+    min: CircularBuffer<(u64, T)>,
+    // candidates for the maximum, decreasing from the oldest to the newest
+    max: CircularBuffer<(u64, T)>,
+}
+
+impl<T: Copy + PartialOrd + Into<f64>> RollingWindow<T> {
+    pub fn new(capacity: usize) -> Self {
+        RollingWindow {
+            values: CircularBuffer::new(capacity),
+            pushed: 0,
+            sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: CircularBuffer::new(capacity),
+            max: CircularBuffer::new(capacity),
+        }
+    }
+
+    /// Add a value, evicting and returning the oldest one when the window is full.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+
+        let evicted = self.values.overwrite(value);
+        if let Some(old) = evicted {
+            self.remove(old);
+        }
+        self.add(value);
+
+        if evicted.is_some_and(|old| self.dwarfs_window(old.into())) {
+            self.recompute();
+        }
+
+        evicted
+    }
+
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then_some(self.mean)
+    }
+
+    /// Population variance of the values in the window.
+    pub fn variance(&self) -> Option<f64> {
+        // rounding can leave a tiny negative remainder
+        (!self.is_empty()).then(|| (self.m2 / self.len() as f64).max(0.0))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.min.front().map(|&(_, value)| value)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.max.front().map(|&(_, value)| value)
+    }
+
+    /// The values in the window, oldest first.
+    pub fn values(&self) -> &CircularBuffer<T> {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.is_full()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    fn add(&mut self, value: T) {
+        let x = value.into();
+        let n = self.len() as f64;
+        self.sum += x;
+        let delta = x - self.mean;
+        self.mean += delta / n;
+        self.m2 += delta * (x - self.mean);
+
+        // values that don't compare can't be a minimum or maximum
+        if value.partial_cmp(&value).is_some() {
+            // a candidate is dropped once a newer value is at least as good: that one
+            // leaves the window later
+            while self.min.back().is_some_and(|&(_, v)| v >= value) {
+                let _ = self.min.pop_back();
+            }
+            while self.max.back().is_some_and(|&(_, v)| v <= value) {
+                let _ = self.max.pop_back();
+            }
+            let _ = self.min.write((self.pushed, value));
+            let _ = self.max.write((self.pushed, value));
+        }
+        self.pushed += 1;
+    }
+
+    /// Whether `x` is so large that taking it out of the statistics left mostly rounding
+    /// errors behind.
+    fn dwarfs_window(&self, x: f64) -> bool {
+        let largest = match (self.min(), self.max()) {
+            (Some(min), Some(max)) => min.into().abs().max(max.into().abs()),
+            // only values that don't compare are left
+            _ => return true,
+        };
+        x.is_nan() || x.abs() > largest * RECOMPUTE_RATIO
+    }
+
+    /// Sum, mean and squared differences from the values in the window alone.
+    fn recompute(&mut self) {
+        let n = self.len() as f64;
+        self.sum = self.values.iter().map(|&v| v.into()).sum();
+        self.mean = self.sum / n;
+        self.m2 = self
+            .values
+            .iter()
+            .map(|&v| {
+                let delta = v.into() - self.mean;
+                delta * delta
+            })
+            .sum();
+    }
+
+    /// Take the oldest value out of the statistics, after `values` replaced it with the
+    /// newest one.
+    fn remove(&mut self, value: T) {
+        let x = value.into();
+        let n = (self.len() - 1) as f64;
+        self.sum -= x;
+        if n == 0.0 {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+        } else {
+            let delta = x - self.mean;
+            self.mean -= delta / n;
+            self.m2 -= delta * (x - self.mean);
+        }
+
+        // the evicted value was pushed `capacity` values ago
+        let oldest = self.pushed - self.capacity() as u64;
+        if self.min.front().is_some_and(|&(i, _)| i == oldest) {
+            let _ = self.min.read();
+        }
+        if self.max.front().is_some_and(|&(i, _)| i == oldest) {
+            let _ = self.max.read();
+        }
+    }
+}
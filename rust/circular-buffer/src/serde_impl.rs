@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{
+    de::Error as _,
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::CircularBuffer;
+
+/// Serialized as its capacity and its elements, oldest first.
+impl<T: Serialize, H> Serialize for CircularBuffer<T, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CircularBuffer", 2)?;
+        state.serialize_field("capacity", &self.capacity())?;
+        state.serialize_field("elements", &Elements(self))?;
+        state.end()
+    }
+}
+
+struct Elements<'a, T, H>(&'a CircularBuffer<T, H>);
+
+impl<T: Serialize, H> Serialize for Elements<'_, T, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "CircularBuffer")]
+struct Repr<T> {
+    capacity: usize,
+    elements: Vec<T>,
+}
+
+/// The eviction hook and the other settings aren't serialized, the buffer comes back
+/// with the defaults.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CircularBuffer<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Repr { capacity, elements } = Repr::deserialize(deserializer)?;
+        if elements.len() > capacity {
+            return Err(D::Error::custom(TooManyElements {
+                capacity,
+                found: elements.len(),
+            }));
+        }
+
+        // the capacity comes from the input, failing to allocate it must not abort
+        let mut buffer = CircularBuffer::try_new(capacity)
+            .ok_or_else(|| D::Error::custom(CapacityTooLarge { capacity }))?;
+        for element in elements {
+            // can't fail: checked against the capacity above
+            let _ = buffer.write(element);
+        }
+        Ok(buffer)
+    }
+}
+
+struct TooManyElements {
+    capacity: usize,
+    found: usize,
+}
+
+impl fmt::Display for TooManyElements {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} elements don't fit a capacity of {}",
+            self.found, self.capacity
+        )
+    }
+}
+
+struct CapacityTooLarge {
+    capacity: usize,
+}
+
+impl fmt::Display for CapacityTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "can't allocate a capacity of {}", self.capacity)
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::mem::MaybeUninit;
+
+use crate::{
+    sync::{Arc, AtomicUsize, Ordering, UnsafeCell},
+    CircularBuffer, Error,
+};
+
+/// Storage shared by a [`Producer`] and a [`Consumer`].
+///
+/// `head` and `tail` count the elements read and written modulo twice the capacity, so
+/// that a full buffer (`tail - head == capacity`) is told apart from an empty one and the
+/// slot of a cursor stays the same when it wraps, whatever the capacity. Only the
+/// consumer stores `head` and only the producer stores `tail`, so a slot is owned by
+/// exactly one side at any time.
+struct Shared<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: the cursors hand every slot to either the producer or the consumer, never to both.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn slot(&self, cursor: usize) -> &UnsafeCell<MaybeUninit<T>> {
+        &self.slots[cursor % self.slots.len()]
+    }
+
+    /// The cursor after `cursor`, wrapped to `[0, 2 * capacity)`.
+    fn advance(&self, cursor: usize) -> usize {
+        if cursor + 1 == 2 * self.slots.len() {
+            0
+        } else {
+            cursor + 1
+        }
+    }
+
+    /// Number of elements between the cursors.
+    fn distance(&self, head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + 2 * self.slots.len() - head
+        }
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let mut head = self.head.load(Ordering::Relaxed);
+        while head != tail {
+            // SAFETY: slots between head and tail hold initialized elements
+            self.slot(head)
+                .with_mut(|slot| unsafe { (*slot).assume_init_drop() });
+            head = self.advance(head);
+        }
+    }
+}
+
+/// Writing half of a split [`CircularBuffer`].
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// Reading half of a split [`CircularBuffer`].
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T, H> CircularBuffer<T, H> {
+    /// Split the buffer into a lock-free producer and consumer that can be moved to
+    /// different threads. The elements already in the buffer are kept, the eviction hook
+    /// is dropped.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        let capacity = self.capacity();
+        let mut len = 0;
+        let slots = self
+            .into_iter()
+            .map(|element| {
+                len += 1;
+                UnsafeCell::new(MaybeUninit::new(element))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .chain((len..capacity).map(|_| UnsafeCell::new(MaybeUninit::uninit())))
+            .collect::<Box<[_]>>();
+
+        let shared = Arc::new(Shared {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(len),
+        });
+
+        (
+            Producer {
+                shared: Arc::clone(&shared),
+            },
+            Consumer { shared },
+        )
+    }
+}
+
+impl<T> Producer<T> {
+    pub fn write(&mut self, element: T) -> Result<(), Error> {
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let head = self.shared.head.load(Ordering::Acquire);
+        if self.shared.distance(head, tail) == self.shared.slots.len() {
+            return Err(Error::FullBuffer);
+        }
+
+        // SAFETY: the slot at tail is free and only the producer writes to free slots
+        self.shared
+            .slot(tail)
+            .with_mut(|slot| unsafe { (*slot).write(element) });
+        self.shared
+            .tail
+            .store(self.shared.advance(tail), Ordering::Release);
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Acquire);
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        self.shared.distance(head, tail)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+}
+
+impl<T> Consumer<T> {
+    pub fn read(&mut self) -> Result<T, Error> {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        if head == tail {
+            return Err(Error::EmptyBuffer);
+        }
+
+        // SAFETY: the slot at head was initialized by the producer before it published tail
+        let element = self
+            .shared
+            .slot(head)
+            .with_mut(|slot| unsafe { (*slot).assume_init_read() });
+        self.shared
+            .head
+            .store(self.shared.advance(head), Ordering::Release);
+
+        Ok(element)
+    }
+
+    pub fn len(&self) -> usize {
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        let head = self.shared.head.load(Ordering::Relaxed);
+        self.shared.distance(head, tail)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.shared.slots.len()
+    }
+}
@@ -0,0 +1,133 @@
+use circular_buffer::{array::CircularBuffer, Error};
+use std::rc::Rc;
+
+#[test]
+fn error_on_read_empty_buffer() {
+    let mut buffer = CircularBuffer::<char, 1>::new();
+    assert_eq!(Err(Error::EmptyBuffer), buffer.read());
+}
+
+#[test]
+fn items_are_read_in_the_order_they_are_written() {
+    let mut buffer = CircularBuffer::<char, 2>::new();
+    assert!(buffer.write('1').is_ok());
+    assert!(buffer.write('2').is_ok());
+    assert_eq!(Err(Error::FullBuffer), buffer.write('3'));
+    assert_eq!(Ok('1'), buffer.read());
+    assert_eq!(Ok('2'), buffer.read());
+    assert_eq!(Err(Error::EmptyBuffer), buffer.read());
+}
+
+#[test]
+fn read_position_is_maintained_even_across_multiple_writes() {
+    let mut buffer = CircularBuffer::<char, 3>::new();
+    assert!(buffer.write('1').is_ok());
+    assert!(buffer.write('2').is_ok());
+    assert_eq!(Ok('1'), buffer.read());
+    assert!(buffer.write('3').is_ok());
+    assert_eq!(Ok('2'), buffer.read());
+    assert_eq!(Ok('3'), buffer.read());
+}
+
+#[test]
+fn overwrite_replaces_the_oldest_item_remaining_in_buffer_following_a_read() {
+    let mut buffer = CircularBuffer::<char, 3>::new();
+    assert!(buffer.write('1').is_ok());
+    assert!(buffer.write('2').is_ok());
+    assert!(buffer.write('3').is_ok());
+    assert_eq!(Ok('1'), buffer.read());
+    assert!(buffer.write('4').is_ok());
+    buffer.overwrite('5');
+    assert_eq!(Ok('3'), buffer.read());
+    assert_eq!(Ok('4'), buffer.read());
+    assert_eq!(Ok('5'), buffer.read());
+}
+
+#[test]
+fn clear_empties_the_buffer() {
+    let mut buffer = CircularBuffer::<char, 3>::new();
+    assert!(buffer.write('1').is_ok());
+    assert!(buffer.write('2').is_ok());
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(Err(Error::EmptyBuffer), buffer.read());
+    assert!(buffer.write('3').is_ok());
+    assert_eq!(Ok('3'), buffer.read());
+}
+
+#[test]
+fn peek_and_size_queries() {
+    let mut buffer = CircularBuffer::<u8, 2>::new();
+    assert_eq!(Err(Error::EmptyBuffer), buffer.peek());
+    assert_eq!(buffer.overwrite(1), None);
+    assert_eq!(buffer.overwrite(2), None);
+    assert_eq!(buffer.overwrite(3), Some(1));
+    assert_eq!(Ok(&2), buffer.peek());
+    assert_eq!(Ok(&3), buffer.peek_back());
+    assert_eq!(buffer.len(), 2);
+    assert!(buffer.is_full());
+    assert_eq!(buffer.capacity(), 2);
+}
+
+#[test]
+fn iterators_wrap_around() {
+    let mut buffer = CircularBuffer::<i32, 4>::new();
+    for i in 0..7 {
+        buffer.overwrite(i);
+    }
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 6]);
+
+    for element in &mut buffer {
+        *element *= 10;
+    }
+    assert_eq!(buffer.drain().collect::<Vec<_>>(), [30, 40, 50, 60]);
+    assert!(buffer.is_empty());
+
+    buffer.write(1).unwrap();
+    assert_eq!(buffer.into_iter().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut buffer = CircularBuffer::<char, 0>::new();
+    assert_eq!(Err(Error::FullBuffer), buffer.write('1'));
+    assert_eq!(Err(Error::EmptyBuffer), buffer.read());
+    assert_eq!(buffer.overwrite('2'), Some('2'));
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.iter().count(), 0);
+}
+
+#[test]
+fn every_element_is_dropped_exactly_once() {
+    let element = Rc::new(());
+    {
+        let mut buffer = CircularBuffer::<_, 3>::new();
+        for _ in 0..5 {
+            buffer.overwrite(Rc::clone(&element));
+        }
+        assert_eq!(Rc::strong_count(&element), 4);
+
+        drop(buffer.read());
+        assert_eq!(Rc::strong_count(&element), 3);
+
+        let mut drain = buffer.drain();
+        drain.next();
+        drop(drain);
+        assert_eq!(Rc::strong_count(&element), 1);
+
+        buffer.write(Rc::clone(&element)).unwrap();
+        buffer.write(Rc::clone(&element)).unwrap();
+        buffer.clear();
+        assert_eq!(Rc::strong_count(&element), 1);
+
+        buffer.write(Rc::clone(&element)).unwrap();
+        buffer.write(Rc::clone(&element)).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&element), 1);
+}
+
+#[test]
+fn usable_in_a_static_context() {
+    static EMPTY: CircularBuffer<u8, 16> = CircularBuffer::new();
+    assert_eq!(EMPTY.len(), 0);
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::CircularBuffer;
+
+#[test]
+fn write_all_or_none_writes_everything_that_fits() {
+    let mut buffer = CircularBuffer::new(4);
+    assert_eq!(buffer.write_all_or_none([1, 2, 3]), Ok(()));
+    assert_eq!(buffer.write_all_or_none(Vec::new()), Ok(()));
+    assert_eq!(buffer.write_all_or_none([4]), Ok(()));
+    assert!(buffer.is_full());
+    assert_eq!(buffer.read_many(4), [1, 2, 3, 4]);
+}
+
+#[test]
+fn write_all_or_none_changes_nothing_when_not_everything_fits() {
+    let mut buffer = CircularBuffer::new(4);
+    buffer.write(0).unwrap();
+    buffer.write(1).unwrap();
+
+    let mut elements = vec![2, 3, 4, 5].into_iter();
+    assert_eq!(buffer.write_all_or_none(&mut elements), Err(vec![2, 3, 4]));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(elements.collect::<Vec<_>>(), [5]);
+}
+
+#[test]
+fn write_all_or_none_takes_iterators_of_unknown_length() {
+    let mut buffer = CircularBuffer::new(3);
+    buffer.write(0).unwrap();
+    assert_eq!(
+        buffer.write_all_or_none((1..).take_while(|&i| i < 3)),
+        Ok(())
+    );
+    assert_eq!(
+        buffer.write_all_or_none((3..).filter(|i| i % 2 == 0)),
+        Err(vec![4])
+    );
+    assert_eq!(buffer.read_many(3), [0, 1, 2]);
+
+    let mut growing = CircularBuffer::new(1).with_auto_grow();
+    assert_eq!(
+        growing.write_all_or_none((0..10).filter(|i| i % 3 == 0)),
+        Ok(())
+    );
+    assert_eq!(growing.read_many(10), [0, 3, 6, 9]);
+}
+
+#[test]
+fn write_all_or_none_grows_in_auto_grow_mode() {
+    let mut buffer = CircularBuffer::new(1).with_auto_grow();
+    assert_eq!(buffer.write_all_or_none(0..5), Ok(()));
+    assert_eq!(buffer.read_many(10), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn write_many_reports_the_remainder() {
+    let mut buffer = CircularBuffer::new(3);
+    buffer.write('a').unwrap();
+
+    let (written, rest) = buffer.write_many("bcde".chars());
+    assert_eq!(written, 2);
+    assert_eq!(rest.collect::<String>(), "de");
+    assert_eq!(buffer.iter().collect::<String>(), "abc");
+
+    let (written, mut rest) = buffer.write_many(vec!['f']);
+    assert_eq!(written, 0);
+    assert_eq!(rest.next(), Some('f'));
+}
+
+#[test]
+fn write_many_takes_everything_that_fits() {
+    let mut buffer = CircularBuffer::new(8);
+    let (written, mut rest) = buffer.write_many(1..=5);
+    assert_eq!(written, 5);
+    assert_eq!(rest.next(), None);
+
+    let mut growing = CircularBuffer::new(0).with_auto_grow();
+    assert_eq!(growing.write_many(0..100).0, 100);
+}
+
+#[test]
+fn read_many_reads_up_to_n() {
+    let mut buffer = CircularBuffer::new(4);
+    for i in 0..6 {
+        buffer.overwrite(i);
+    }
+    assert_eq!(buffer.read_many(3), [2, 3, 4]);
+    assert_eq!(buffer.read_many(3), [5]);
+    assert!(buffer.read_many(3).is_empty());
+    assert_eq!(buffer.write_many(6..8).0, 2);
+    assert_eq!(buffer.read_many(0), Vec::<i32>::new());
+    assert_eq!(buffer.len(), 2);
+}
+
+#[test]
+fn zero_capacity_batches() {
+    let mut buffer = CircularBuffer::new(0);
+    assert_eq!(buffer.write_all_or_none(Vec::new()), Ok(()));
+    assert_eq!(buffer.write_all_or_none([1]), Err(vec![1]));
+    assert_eq!(buffer.write_many([1]).0, 0);
+    assert!(buffer.read_many(1).is_empty());
+}
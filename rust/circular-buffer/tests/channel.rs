@@ -0,0 +1,132 @@
+#![cfg(all(feature = "async", not(loom)))]
+
+use circular_buffer::{
+    channel::{channel, lossy_channel, SendError},
+    Error,
+};
+use futures::{task::noop_waker_ref, SinkExt, StreamExt};
+use std::{
+    task::{Context, Poll},
+    time::Duration,
+};
+
+#[tokio::test]
+async fn values_arrive_in_order() {
+    let (mut sender, receiver) = channel(2);
+    let reader = tokio::spawn(receiver.collect::<Vec<_>>());
+
+    for i in 0..10 {
+        sender.send(i).await.unwrap();
+    }
+    drop(sender);
+
+    assert_eq!(reader.await.unwrap(), (0..10).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn sender_waits_for_free_capacity() {
+    let (mut sender, mut receiver) = channel(1);
+    sender.send('1').await.unwrap();
+    assert_eq!(Err(SendError::Full), sender.try_send('2'));
+
+    let blocked = tokio::time::timeout(Duration::from_millis(20), sender.send('2')).await;
+    assert!(blocked.is_err());
+
+    let writer = tokio::spawn(async move { sender.send('3').await });
+    assert_eq!(Some('1'), receiver.next().await);
+    writer.await.unwrap().unwrap();
+    assert_eq!(Some('3'), receiver.next().await);
+    assert_eq!(None, receiver.next().await);
+}
+
+#[tokio::test]
+async fn receiver_waits_for_values() {
+    let (mut sender, mut receiver) = channel(4);
+    assert_eq!(Err(Error::EmptyBuffer), receiver.try_recv());
+
+    let reader = tokio::spawn(async move { receiver.next().await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    sender.send(42).await.unwrap();
+    assert_eq!(Some(42), reader.await.unwrap());
+}
+
+#[tokio::test]
+async fn stream_ends_when_all_senders_are_gone() {
+    let (mut sender, mut receiver) = channel(4);
+    let mut other = sender.clone();
+    sender.send(1).await.unwrap();
+    other.send(2).await.unwrap();
+    drop(sender);
+    other.close().await.unwrap();
+
+    assert_eq!(Some(1), receiver.next().await);
+    assert_eq!(Some(2), receiver.next().await);
+    assert_eq!(None, receiver.next().await);
+}
+
+#[tokio::test]
+async fn sending_to_dropped_receiver_fails() {
+    let (mut sender, receiver) = channel(4);
+    drop(receiver);
+    assert_eq!(Err(SendError::Closed), sender.send(1).await);
+    assert_eq!(Err(SendError::Closed), sender.try_send(1));
+}
+
+#[tokio::test]
+async fn dropping_the_receiver_wakes_waiting_senders() {
+    let (mut sender, receiver) = channel(1);
+    sender.send(1).await.unwrap();
+
+    let writer = tokio::spawn(async move { sender.send(2).await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    drop(receiver);
+    assert_eq!(Err(SendError::Closed), writer.await.unwrap());
+}
+
+#[tokio::test]
+async fn lossy_channel_never_blocks_writers() {
+    let (mut sender, mut receiver) = lossy_channel(3);
+    for i in 0..10 {
+        sender.send(i).await.unwrap();
+    }
+    assert!(sender.try_send(10).is_ok());
+    drop(sender);
+
+    assert_eq!(receiver.by_ref().collect::<Vec<_>>().await, [8, 9, 10]);
+}
+
+#[test]
+fn poll_ready_reserves_the_slot_for_start_send() {
+    let (mut first, receiver) = channel(1);
+    let mut second = first.clone();
+    let mut cx = Context::from_waker(noop_waker_ref());
+
+    assert_eq!(Poll::Ready(Ok(())), first.poll_ready_unpin(&mut cx));
+    assert_eq!(Poll::Pending, second.poll_ready_unpin(&mut cx));
+    assert_eq!(Err(SendError::Full), second.try_send('2'));
+    assert_eq!(Ok(()), first.start_send_unpin('1'));
+
+    assert_eq!(Ok('1'), receiver.try_recv());
+    assert_eq!(Poll::Ready(Ok(())), second.poll_ready_unpin(&mut cx));
+    assert_eq!(Ok(()), second.start_send_unpin('2'));
+    assert_eq!(Ok('2'), receiver.try_recv());
+}
+
+#[test]
+fn dropping_a_sender_releases_its_reservation() {
+    let (mut first, receiver) = channel(1);
+    let mut second = first.clone();
+    let mut cx = Context::from_waker(noop_waker_ref());
+
+    assert_eq!(Poll::Ready(Ok(())), first.poll_ready_unpin(&mut cx));
+    drop(first);
+    assert_eq!(Poll::Ready(Ok(())), second.poll_ready_unpin(&mut cx));
+    assert_eq!(Ok(()), second.start_send_unpin('1'));
+    assert_eq!(Ok('1'), receiver.try_recv());
+}
+
+#[test]
+#[should_panic(expected = "channel without capacity")]
+fn channel_without_capacity_is_rejected() {
+    let _ = channel::<char>(0);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use circular_buffer::{CircularBuffer, Error};
 use std::rc::Rc;
 
@@ -148,3 +150,48 @@ fn string_buffer() {
     assert_eq!(0, buffer.read().unwrap().len());
     assert_eq!(Ok("Testing".to_string()), buffer.read());
 }
+
+#[test]
+fn elements_left_in_the_buffer_are_dropped_with_it() {
+    let element = Rc::new(());
+    let mut buffer = CircularBuffer::new(3);
+    for _ in 0..5 {
+        buffer.overwrite(Rc::clone(&element));
+    }
+    assert_eq!(Rc::strong_count(&element), 4);
+    drop(buffer);
+    assert_eq!(Rc::strong_count(&element), 1);
+}
+
+#[test]
+fn clear_empties_the_whole_buffer() {
+    let mut buffer = CircularBuffer::new(3);
+    let element = Rc::new(());
+    for _ in 0..3 {
+        assert!(buffer.write(Rc::clone(&element)).is_ok());
+    }
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(Rc::strong_count(&element), 1);
+    assert!(buffer.write(Rc::clone(&element)).is_ok());
+    assert_eq!(Ok(element), buffer.read());
+}
+
+#[test]
+fn truncate_keeps_the_oldest_elements() {
+    let mut buffer: CircularBuffer<_> = (1..=4).collect();
+    buffer.truncate(2);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [1, 2]);
+    buffer.truncate(5);
+    assert_eq!(buffer.len(), 2);
+}
+
+#[test]
+fn retain_keeps_the_order() {
+    let mut buffer = CircularBuffer::new(5);
+    for i in 0..8 {
+        buffer.overwrite(i);
+    }
+    buffer.retain(|x| x % 2 == 1);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 5, 7]);
+}
@@ -0,0 +1,18 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use circular_buffer::CircularBuffer;
+
+/// A buffer holding 3, 4, 5 with the elements wrapped around the end of its storage.
+pub fn wrapped() -> CircularBuffer<i32> {
+    let mut buffer = CircularBuffer::new(4);
+    for i in 0..6 {
+        buffer.overwrite(i);
+    }
+    buffer.read().unwrap();
+    buffer
+}
+
+pub fn contents<H>(buffer: &CircularBuffer<i32, H>) -> Vec<i32> {
+    buffer.iter().copied().collect()
+}
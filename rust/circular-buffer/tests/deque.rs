@@ -0,0 +1,130 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::{CircularBuffer, Error};
+use std::rc::Rc;
+
+mod common;
+
+use common::{contents, wrapped};
+
+#[test]
+fn get_is_relative_to_the_oldest_element() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.get(0), Some(&3));
+    assert_eq!(buffer.get(2), Some(&5));
+    assert_eq!(buffer.get(3), None);
+
+    *buffer.get_mut(1).unwrap() = 40;
+    assert_eq!(buffer.get_mut(3), None);
+    assert_eq!(contents(&buffer), [3, 40, 5]);
+}
+
+#[test]
+fn k_th_newest_element() {
+    let buffer = wrapped();
+    let newest = |k: usize| buffer.get(buffer.len() - 1 - k);
+    assert_eq!(newest(0), Some(&5));
+    assert_eq!(newest(2), Some(&3));
+}
+
+#[test]
+fn indexing() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer[0], 3);
+    buffer[2] += 10;
+    assert_eq!(buffer[2], 15);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds for 3 elements")]
+fn indexing_past_the_newest_element_panics() {
+    let buffer = wrapped();
+    let _ = buffer[3];
+}
+
+#[test]
+fn front_and_back() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.front(), Some(&3));
+    assert_eq!(buffer.back(), Some(&5));
+
+    buffer.clear();
+    assert_eq!(buffer.front(), None);
+    assert_eq!(buffer.back(), None);
+}
+
+#[test]
+fn push_front_is_read_next() {
+    let mut buffer = wrapped();
+    assert!(buffer.push_front(2).is_ok());
+    assert_eq!(Err(Error::FullBuffer), buffer.push_front(1));
+    assert_eq!(contents(&buffer), [2, 3, 4, 5]);
+    assert_eq!(buffer.read(), Ok(2));
+
+    let mut growing = CircularBuffer::new(1).with_auto_grow();
+    growing.push_front('b').unwrap();
+    growing.push_front('a').unwrap();
+    assert_eq!(growing.read(), Ok('a'));
+}
+
+#[test]
+fn pop_back_reads_the_newest() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.pop_back(), Ok(5));
+    assert_eq!(buffer.pop_back(), Ok(4));
+    assert!(buffer.write(6).is_ok());
+    assert_eq!(contents(&buffer), [3, 6]);
+    assert_eq!(buffer.pop_back(), Ok(6));
+    assert_eq!(buffer.pop_back(), Ok(3));
+    assert_eq!(Err(Error::EmptyBuffer), buffer.pop_back());
+}
+
+#[test]
+fn rotate_left() {
+    let mut buffer = wrapped();
+    buffer.rotate_left(1);
+    assert_eq!(contents(&buffer), [4, 5, 3]);
+    buffer.rotate_left(3);
+    assert_eq!(contents(&buffer), [4, 5, 3]);
+    buffer.rotate_left(0);
+    assert_eq!(contents(&buffer), [4, 5, 3]);
+
+    buffer.write(6).unwrap();
+    buffer.rotate_left(3);
+    assert_eq!(contents(&buffer), [6, 4, 5, 3]);
+    assert_eq!(buffer.read(), Ok(6));
+}
+
+#[test]
+#[should_panic]
+fn rotate_left_past_the_length_panics() {
+    wrapped().rotate_left(4);
+}
+
+#[test]
+fn make_contiguous() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.as_slices(), (&[3][..], &[4, 5][..]));
+    assert_eq!(buffer.make_contiguous(), [3, 4, 5]);
+    assert_eq!(buffer.as_slices(), (&[3, 4, 5][..], &[][..]));
+
+    buffer.make_contiguous().reverse();
+    assert_eq!(contents(&buffer), [5, 4, 3]);
+    buffer.write(6).unwrap();
+    assert_eq!(buffer.make_contiguous(), [5, 4, 3, 6]);
+}
+
+#[test]
+fn deque_operations_drop_elements_once() {
+    let element = Rc::new(());
+    let mut buffer = CircularBuffer::new(3);
+    buffer.push_front(Rc::clone(&element)).unwrap();
+    buffer.push_front(Rc::clone(&element)).unwrap();
+    buffer.write(Rc::clone(&element)).unwrap();
+    buffer.rotate_left(2);
+    buffer.make_contiguous();
+    drop(buffer.pop_back());
+    assert_eq!(Rc::strong_count(&element), 3);
+    drop(buffer);
+    assert_eq!(Rc::strong_count(&element), 1);
+}
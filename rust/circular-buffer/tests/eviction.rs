@@ -0,0 +1,79 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::CircularBuffer;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+#[test]
+fn overwrite_returns_the_evicted_element() {
+    let mut buffer = CircularBuffer::new(2);
+    assert_eq!(buffer.overwrite('1'), None);
+    assert_eq!(buffer.overwrite('2'), None);
+    assert_eq!(buffer.overwrite('3'), Some('1'));
+    assert_eq!(buffer.read(), Ok('2'));
+    assert_eq!(buffer.overwrite('4'), None);
+    assert_eq!(buffer.overwrite('5'), Some('3'));
+}
+
+#[test]
+fn eviction_hook_counts_dropped_elements() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&dropped);
+    let mut buffer = CircularBuffer::new(3).with_eviction_hook(move |_: &u32| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    for i in 0..10 {
+        buffer.overwrite(i);
+    }
+    assert_eq!(dropped.load(Ordering::Relaxed), 7);
+
+    // only evictions are reported, not reads or rejected writes
+    buffer.read().unwrap();
+    assert!(buffer.write(10).is_ok());
+    assert!(buffer.write(11).is_err());
+    assert_eq!(dropped.load(Ordering::Relaxed), 7);
+}
+
+struct Forward {
+    sink: Arc<Mutex<Vec<String>>>,
+}
+
+impl circular_buffer::EvictionHook<String> for Forward {
+    fn evicted(&self, element: &String) {
+        self.sink.lock().unwrap().push(element.clone());
+    }
+}
+
+#[test]
+fn eviction_hook_sees_the_evicted_elements() {
+    let sink = Arc::new(Mutex::new(Vec::new()));
+    let mut buffer = CircularBuffer::new(2).with_eviction_hook(Forward {
+        sink: Arc::clone(&sink),
+    });
+
+    for word in ["a", "b", "c", "d"] {
+        buffer.overwrite(word.to_string());
+    }
+    assert_eq!(*sink.lock().unwrap(), ["a", "b"]);
+    assert_eq!(
+        buffer.into_iter().collect::<Vec<_>>(),
+        ["c".to_string(), "d".to_string()]
+    );
+}
+
+#[test]
+fn buffer_is_covariant_in_its_elements() {
+    fn shorten<'a>(buffer: CircularBuffer<&'static str>) -> CircularBuffer<&'a str> {
+        buffer
+    }
+
+    let mut buffer = CircularBuffer::new(2);
+    buffer.write("static").unwrap();
+    let local = String::from("local");
+    let mut buffer = shorten(buffer);
+    buffer.write(&local).unwrap();
+    assert_eq!(buffer.into_iter().collect::<Vec<_>>(), ["static", "local"]);
+}
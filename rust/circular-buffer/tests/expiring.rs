@@ -0,0 +1,97 @@
+#![cfg(feature = "std")]
+
+use circular_buffer::{Clock, Error, ExpiringBuffer, FakeClock, SystemClock};
+use std::time::Duration;
+
+const TTL: Duration = Duration::from_secs(10);
+
+fn buffer(capacity: usize) -> (ExpiringBuffer<char, FakeClock>, FakeClock) {
+    let clock = FakeClock::new();
+    (
+        ExpiringBuffer::with_clock(capacity, TTL, clock.clone()),
+        clock,
+    )
+}
+
+#[test]
+fn elements_expire_after_the_ttl() {
+    let (mut buffer, clock) = buffer(4);
+    buffer.write('1').unwrap();
+    clock.advance(Duration::from_secs(4));
+    buffer.write('2').unwrap();
+    assert_eq!(buffer.len(), 2);
+
+    clock.advance(Duration::from_secs(6));
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.read(), Ok('2'));
+
+    buffer.write('3').unwrap();
+    clock.advance(TTL);
+    assert_eq!(buffer.read(), Err(Error::EmptyBuffer));
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn expired_elements_free_capacity() {
+    let (mut buffer, clock) = buffer(2);
+    buffer.write('1').unwrap();
+    buffer.write('2').unwrap();
+    assert_eq!(buffer.write('3'), Err(Error::FullBuffer));
+
+    clock.advance(TTL);
+    assert!(buffer.write('3').is_ok());
+    assert_eq!(buffer.iter().collect::<Vec<_>>(), [&'3']);
+}
+
+#[test]
+fn overwrite_evicts_only_live_elements() {
+    let (mut buffer, clock) = buffer(2);
+    buffer.write('1').unwrap();
+    clock.advance(Duration::from_secs(5));
+    buffer.write('2').unwrap();
+    assert_eq!(buffer.overwrite('3'), Some('1'));
+
+    clock.advance(TTL);
+    assert_eq!(buffer.overwrite('4'), None);
+    assert_eq!(buffer.iter().collect::<Vec<_>>(), [&'4']);
+}
+
+#[test]
+fn expire_reports_how_many_expired() {
+    let (mut buffer, clock) = buffer(4);
+    buffer.write('1').unwrap();
+    buffer.write('2').unwrap();
+    clock.advance(Duration::from_secs(1));
+    buffer.write('3').unwrap();
+
+    clock.advance(Duration::from_secs(9));
+    assert_eq!(buffer.expire(), 2);
+    assert_eq!(buffer.expire(), 0);
+}
+
+#[test]
+fn count_within_the_last_seconds() {
+    let (mut buffer, clock) = buffer(16);
+    for _ in 0..5 {
+        buffer.write('.').unwrap();
+        clock.advance(Duration::from_secs(1));
+    }
+
+    // written 5, 4, 3, 2 and 1 seconds ago
+    assert_eq!(buffer.count_within(Duration::from_secs(1)), 0);
+    assert_eq!(buffer.count_within(Duration::from_millis(1500)), 1);
+    assert_eq!(buffer.count_within(Duration::from_secs(3)), 2);
+    assert_eq!(buffer.count_within(Duration::from_secs(60)), 5);
+
+    clock.advance(Duration::from_secs(6));
+    assert_eq!(buffer.count_within(Duration::from_secs(60)), 3);
+}
+
+#[test]
+fn system_clock_moves_forward() {
+    let before = SystemClock.now();
+    let mut buffer = ExpiringBuffer::new(1, Duration::from_secs(3600));
+    buffer.write(1).unwrap();
+    assert_eq!(buffer.len(), 1);
+    assert!(SystemClock.now() >= before);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "std")]
+
+use circular_buffer::CircularBuffer;
+use std::io::{BufRead, ErrorKind, Read, Write};
+
+#[test]
+fn as_slices_splits_where_the_elements_wrap() {
+    let mut buffer = CircularBuffer::new(4);
+    assert_eq!(buffer.as_slices(), (&[][..], &[][..]));
+
+    for i in 1..=4 {
+        buffer.write(i).unwrap();
+    }
+    assert_eq!(buffer.as_slices(), (&[1, 2, 3, 4][..], &[][..]));
+
+    buffer.overwrite(5);
+    buffer.overwrite(6);
+    assert_eq!(buffer.as_slices(), (&[3, 4][..], &[5, 6][..]));
+
+    buffer.as_mut_slices().1[0] = 50;
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 50, 6]);
+}
+
+#[test]
+fn write_slice_writes_what_fits() {
+    let mut buffer = CircularBuffer::new(4);
+    assert_eq!(buffer.write_slice(&[1, 2, 3]), 3);
+    assert_eq!(buffer.read(), Ok(1));
+    assert_eq!(buffer.write_slice(&[4, 5, 6]), 2);
+    assert_eq!(buffer.write_slice(&[7]), 0);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+    assert_eq!(buffer.as_slices(), (&[2, 3, 4][..], &[5][..]));
+}
+
+#[test]
+fn read_into_reads_what_fits() {
+    let mut buffer = CircularBuffer::new(4);
+    buffer.write_slice(&[1, 2, 3, 4]);
+    buffer.read().unwrap();
+    buffer.read().unwrap();
+    buffer.write_slice(&[5, 6]);
+
+    let mut out = [0; 3];
+    assert_eq!(buffer.read_into(&mut out), 3);
+    assert_eq!(out, [3, 4, 5]);
+    assert_eq!(buffer.read_into(&mut out), 1);
+    assert_eq!(out[0], 6);
+    assert_eq!(buffer.read_into(&mut out), 0);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn bulk_copies_on_zero_capacity() {
+    let mut buffer = CircularBuffer::<u8>::new(0);
+    assert_eq!(buffer.write_slice(b"abc"), 0);
+    assert_eq!(buffer.read_into(&mut [0; 3]), 0);
+}
+
+#[test]
+fn io_read_and_write_stage_bytes() {
+    let mut buffer = CircularBuffer::new(8);
+    buffer.write_all(b"hello").unwrap();
+    let mut out = [0; 3];
+    buffer.read_exact(&mut out).unwrap();
+    assert_eq!(&out, b"hel");
+
+    buffer.write_all(b" world").unwrap();
+    let mut out = String::new();
+    buffer.read_to_string(&mut out).unwrap();
+    assert_eq!(out, "lo world");
+}
+
+#[test]
+fn io_write_to_a_full_buffer_writes_zero() {
+    let mut buffer = CircularBuffer::<u8>::new(4);
+    // the inherent `write` takes a single element
+    assert_eq!(Write::write(&mut buffer, b"abcdef").unwrap(), 4);
+    assert_eq!(Write::write(&mut buffer, b"g").unwrap(), 0);
+    assert_eq!(
+        buffer.write_all(b"g").unwrap_err().kind(),
+        ErrorKind::WriteZero
+    );
+}
+
+#[test]
+fn buf_read_returns_contiguous_runs() {
+    let mut buffer = CircularBuffer::new(8);
+    buffer.write_all(b"abcdefgh").unwrap();
+    buffer.consume(6);
+    buffer.write_all(b"ij\nkl").unwrap();
+
+    assert_eq!(buffer.fill_buf().unwrap(), b"gh");
+    let mut line = String::new();
+    buffer.read_line(&mut line).unwrap();
+    assert_eq!(line, "ghij\n");
+    assert_eq!(buffer.fill_buf().unwrap(), b"kl");
+    buffer.consume(10);
+    assert!(buffer.is_empty());
+}
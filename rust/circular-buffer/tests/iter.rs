@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::{CircularBuffer, Error};
+use std::rc::Rc;
+
+mod common;
+
+use common::wrapped;
+
+#[test]
+fn peek_does_not_consume() {
+    let mut buffer = CircularBuffer::new(2);
+    assert!(buffer.write('1').is_ok());
+    assert!(buffer.write('2').is_ok());
+    assert_eq!(Ok(&'1'), buffer.peek());
+    assert_eq!(Ok(&'2'), buffer.peek_back());
+    assert_eq!(Ok('1'), buffer.read());
+    assert_eq!(Ok(&'2'), buffer.peek());
+    assert_eq!(Ok(&'2'), buffer.peek_back());
+}
+
+#[test]
+fn peek_on_empty_buffer() {
+    let buffer = CircularBuffer::<char>::new(2);
+    assert_eq!(Err(Error::EmptyBuffer), buffer.peek());
+    assert_eq!(Err(Error::EmptyBuffer), buffer.peek_back());
+}
+
+#[test]
+fn peek_after_overwrite() {
+    let mut buffer = CircularBuffer::new(2);
+    buffer.overwrite(1);
+    buffer.overwrite(2);
+    buffer.overwrite(3);
+    assert_eq!(Ok(&2), buffer.peek());
+    assert_eq!(Ok(&3), buffer.peek_back());
+}
+
+#[test]
+fn len_and_capacity() {
+    let mut buffer = CircularBuffer::new(3);
+    assert_eq!(buffer.capacity(), 3);
+    assert_eq!(buffer.len(), 0);
+    assert!(buffer.is_empty());
+    assert!(!buffer.is_full());
+
+    buffer.write('1').unwrap();
+    assert_eq!(buffer.len(), 1);
+    assert!(!buffer.is_empty());
+
+    buffer.write('2').unwrap();
+    buffer.write('3').unwrap();
+    assert_eq!(buffer.len(), 3);
+    assert!(buffer.is_full());
+
+    buffer.read().unwrap();
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.capacity(), 3);
+}
+
+#[test]
+fn iter_in_fifo_order() {
+    let buffer = wrapped();
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(buffer.iter().len(), 3);
+    assert_eq!(buffer.len(), 3);
+}
+
+#[test]
+fn iter_on_full_buffer() {
+    let mut buffer = CircularBuffer::new(3);
+    for i in 0..5 {
+        buffer.overwrite(i);
+    }
+    assert_eq!(
+        (&buffer).into_iter().copied().collect::<Vec<_>>(),
+        [2, 3, 4]
+    );
+}
+
+#[test]
+fn iter_mut_changes_elements() {
+    let mut buffer = wrapped();
+    for element in buffer.iter_mut() {
+        *element *= 10;
+    }
+    for element in &mut buffer {
+        *element += 1;
+    }
+    assert_eq!(Ok(31), buffer.read());
+    assert_eq!(Ok(41), buffer.read());
+    assert_eq!(Ok(51), buffer.read());
+}
+
+#[test]
+fn into_iter_in_fifo_order() {
+    let buffer = wrapped();
+    assert_eq!(buffer.into_iter().collect::<Vec<_>>(), [3, 4, 5]);
+}
+
+#[test]
+fn drain_empties_the_buffer() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.drain().collect::<Vec<_>>(), [3, 4, 5]);
+    assert!(buffer.is_empty());
+    assert!(buffer.write(5).is_ok());
+}
+
+#[test]
+fn dropped_drain_drops_remaining_elements() {
+    let element = Rc::new(());
+    let mut buffer = CircularBuffer::new(3);
+    for _ in 0..3 {
+        buffer.write(Rc::clone(&element)).unwrap();
+    }
+
+    let mut drain = buffer.drain();
+    drain.next();
+    drop(drain);
+
+    assert!(buffer.is_empty());
+    assert_eq!(Rc::strong_count(&element), 1);
+}
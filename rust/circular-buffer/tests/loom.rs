@@ -0,0 +1,75 @@
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --test loom --release`.
+#![cfg(loom)]
+
+use circular_buffer::{CircularBuffer, Error};
+use loom::{sync::Arc, thread};
+
+#[test]
+fn write_then_read_across_threads() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = CircularBuffer::new(2).split();
+
+        let writer = thread::spawn(move || {
+            for i in 0..3 {
+                while producer.write(i).is_err() {
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut expected = 0;
+        while expected < 3 {
+            match consumer.read() {
+                Ok(value) => {
+                    assert_eq!(value, expected);
+                    expected += 1;
+                }
+                Err(Error::EmptyBuffer) => thread::yield_now(),
+                Err(err) => panic!("{:?}", err),
+            }
+        }
+
+        writer.join().unwrap();
+    });
+}
+
+#[test]
+fn full_and_empty_are_reported_consistently() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = CircularBuffer::new(1).split();
+
+        let writer = thread::spawn(move || {
+            let first = producer.write(1);
+            let second = producer.write(2);
+            // the second write only fails when the reader didn't take the first element yet
+            assert!(first.is_ok());
+            second
+        });
+
+        let read = consumer.read();
+        let second = writer.join().unwrap();
+        match (read, second) {
+            (Ok(1), Ok(())) => assert_eq!(Ok(2), consumer.read()),
+            (Ok(1), Err(Error::FullBuffer)) => assert_eq!(Err(Error::EmptyBuffer), consumer.read()),
+            (Err(Error::EmptyBuffer), Err(Error::FullBuffer)) => {
+                assert_eq!(Ok(1), consumer.read())
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn dropping_both_halves_drops_elements() {
+    loom::model(|| {
+        let element = Arc::new(());
+        let (mut producer, consumer) = CircularBuffer::new(2).split();
+        producer.write(Arc::clone(&element)).unwrap();
+
+        let dropper = thread::spawn(move || drop(consumer));
+        drop(producer);
+        dropper.join().unwrap();
+
+        assert_eq!(Arc::strong_count(&element), 1);
+    });
+}
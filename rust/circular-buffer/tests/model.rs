@@ -0,0 +1,187 @@
+//! Model-based tests: random sequences of operations applied to a [`CircularBuffer`] and
+//! to a `VecDeque` with the same capacity have to give the same results.
+#![cfg(feature = "alloc")]
+
+use circular_buffer::{CircularBuffer, Error};
+use proptest::prelude::*;
+use std::{collections::VecDeque, rc::Rc};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Write(u8),
+    Read,
+    Overwrite(u8),
+    Clear,
+    Truncate(usize),
+    // keep the elements divisible by the value
+    Retain(u8),
+    PushFront(u8),
+    PopBack,
+    RotateLeft(usize),
+    MakeContiguous,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => any::<u8>().prop_map(Op::Write),
+        4 => Just(Op::Read),
+        4 => any::<u8>().prop_map(Op::Overwrite),
+        1 => Just(Op::Clear),
+        1 => (0..6usize).prop_map(Op::Truncate),
+        1 => (1..4u8).prop_map(Op::Retain),
+        2 => any::<u8>().prop_map(Op::PushFront),
+        2 => Just(Op::PopBack),
+        1 => (0..6usize).prop_map(Op::RotateLeft),
+        1 => Just(Op::MakeContiguous),
+    ]
+}
+
+/// Apply `op` to the model, a `VecDeque` that may hold at most `capacity` elements.
+fn apply_to_model(model: &mut VecDeque<u8>, capacity: usize, op: &Op) -> Option<Result<u8, Error>> {
+    let full = model.len() == capacity;
+    match *op {
+        Op::Write(_) if full => Some(Err(Error::FullBuffer)),
+        Op::Write(x) => {
+            model.push_back(x);
+            None
+        }
+        Op::Read => Some(model.pop_front().ok_or(Error::EmptyBuffer)),
+        Op::Overwrite(x) if capacity == 0 => Some(Ok(x)),
+        Op::Overwrite(x) => {
+            let evicted = if full { model.pop_front() } else { None };
+            model.push_back(x);
+            evicted.map(Ok)
+        }
+        Op::Clear => {
+            model.clear();
+            None
+        }
+        Op::Truncate(len) => {
+            model.truncate(len);
+            None
+        }
+        Op::Retain(divisor) => {
+            model.retain(|x| x % divisor == 0);
+            None
+        }
+        Op::PushFront(_) if full => Some(Err(Error::FullBuffer)),
+        Op::PushFront(x) => {
+            model.push_front(x);
+            None
+        }
+        Op::PopBack => Some(model.pop_back().ok_or(Error::EmptyBuffer)),
+        Op::RotateLeft(n) => {
+            model.rotate_left(n.min(model.len()));
+            None
+        }
+        Op::MakeContiguous => {
+            model.make_contiguous();
+            None
+        }
+    }
+}
+
+fn apply_to_buffer(buffer: &mut CircularBuffer<u8>, op: &Op) -> Option<Result<u8, Error>> {
+    match *op {
+        Op::Write(x) => buffer.write(x).err().map(Err),
+        Op::Read => Some(buffer.read()),
+        Op::Overwrite(x) => buffer.overwrite(x).map(Ok),
+        Op::Clear => {
+            buffer.clear();
+            None
+        }
+        Op::Truncate(len) => {
+            buffer.truncate(len);
+            None
+        }
+        Op::Retain(divisor) => {
+            buffer.retain(|x| x % divisor == 0);
+            None
+        }
+        Op::PushFront(x) => buffer.push_front(x).err().map(Err),
+        Op::PopBack => Some(buffer.pop_back()),
+        Op::RotateLeft(n) => {
+            buffer.rotate_left(n.min(buffer.len()));
+            None
+        }
+        Op::MakeContiguous => {
+            buffer.make_contiguous();
+            None
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn behaves_like_a_bounded_vec_deque(
+        capacity in 0..6usize,
+        ops in prop::collection::vec(op(), 0..64),
+    ) {
+        let mut buffer = CircularBuffer::new(capacity);
+        let mut model = VecDeque::new();
+
+        for op in &ops {
+            let expected = apply_to_model(&mut model, capacity, op);
+            prop_assert_eq!(apply_to_buffer(&mut buffer, op), expected, "{:?}", op);
+
+            prop_assert_eq!(buffer.iter().copied().collect::<VecDeque<_>>(), model.clone());
+            prop_assert_eq!(buffer.len(), model.len());
+            prop_assert_eq!(buffer.is_empty(), model.is_empty());
+            prop_assert_eq!(buffer.is_full(), model.len() == capacity);
+            prop_assert_eq!(buffer.capacity(), capacity);
+            prop_assert_eq!(buffer.front(), model.front());
+            prop_assert_eq!(buffer.back(), model.back());
+        }
+    }
+
+    #[test]
+    fn every_element_is_dropped_exactly_once(
+        capacity in 0..6usize,
+        ops in prop::collection::vec(op(), 0..64),
+    ) {
+        let element = Rc::new(());
+        {
+            let mut buffer = CircularBuffer::new(capacity);
+            for op in &ops {
+                let clone = || Rc::clone(&element);
+                match *op {
+                    Op::Write(_) => drop(buffer.write(clone())),
+                    Op::Read => drop(buffer.read()),
+                    Op::Overwrite(_) => drop(buffer.overwrite(clone())),
+                    Op::Clear => buffer.clear(),
+                    Op::Truncate(len) => buffer.truncate(len),
+                    Op::Retain(divisor) => {
+                        let mut n = 0;
+                        buffer.retain(|_| {
+                            n += 1;
+                            n % divisor == 0
+                        });
+                    }
+                    Op::PushFront(_) => drop(buffer.push_front(clone())),
+                    Op::PopBack => drop(buffer.pop_back()),
+                    Op::RotateLeft(n) => buffer.rotate_left(n.min(buffer.len())),
+                    Op::MakeContiguous => drop(buffer.make_contiguous()),
+                }
+                prop_assert_eq!(Rc::strong_count(&element), 1 + buffer.len());
+            }
+        }
+        prop_assert_eq!(Rc::strong_count(&element), 1);
+    }
+}
+
+#[test]
+fn zero_capacity_buffer() {
+    let mut buffer = CircularBuffer::new(0);
+    assert_eq!(Err(Error::FullBuffer), buffer.write('1'));
+    assert_eq!(Err(Error::EmptyBuffer), buffer.read());
+    assert_eq!(buffer.overwrite('2'), Some('2'));
+    assert_eq!(Err(Error::FullBuffer), buffer.push_front('3'));
+    assert_eq!(Err(Error::EmptyBuffer), buffer.pop_back());
+    assert_eq!(Err(Error::EmptyBuffer), buffer.peek());
+    assert_eq!(Err(Error::EmptyBuffer), buffer.peek_back());
+    buffer.clear();
+    buffer.extend(['4', '5']);
+    assert!(buffer.is_empty());
+    assert!(buffer.is_full());
+    assert_eq!(buffer.iter().count(), 0);
+}
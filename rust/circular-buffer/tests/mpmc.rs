@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use circular_buffer::{BoundedQueue, CircularBuffer, Error};
+use std::{
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn try_operations_report_full_and_empty() {
+    let queue = BoundedQueue::new(1);
+    assert_eq!(Err(Error::EmptyBuffer), queue.try_read());
+    assert!(queue.try_write('1').is_ok());
+    assert_eq!(Err(Error::FullBuffer), queue.try_write('2'));
+    assert_eq!(Ok('1'), queue.try_read());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn keeps_elements_of_the_wrapped_buffer() {
+    let mut buffer = CircularBuffer::new(2);
+    buffer.write(1).unwrap();
+    let queue = BoundedQueue::from(buffer);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.capacity(), 2);
+    assert_eq!(1, queue.read_blocking());
+}
+
+#[test]
+fn timeouts_give_up() {
+    let queue = BoundedQueue::new(1);
+    let timeout = Duration::from_millis(20);
+
+    let start = Instant::now();
+    assert_eq!(Err(Error::EmptyBuffer), queue.read_timeout(timeout));
+    assert!(start.elapsed() >= timeout);
+
+    queue.write_timeout(1, timeout).unwrap();
+    assert!(queue.is_full());
+    let start = Instant::now();
+    assert_eq!(Err(Error::FullBuffer), queue.write_timeout(2, timeout));
+    assert!(start.elapsed() >= timeout);
+    assert_eq!(Ok(1), queue.read_timeout(timeout));
+}
+
+#[test]
+fn blocking_write_waits_for_a_reader() {
+    let queue = Arc::new(BoundedQueue::new(1));
+    queue.write_blocking(1);
+
+    let writer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.write_blocking(2))
+    };
+
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(1, queue.read_blocking());
+    writer.join().unwrap();
+    assert_eq!(2, queue.read_blocking());
+}
+
+#[test]
+fn timed_read_is_woken_by_a_writer() {
+    let queue = Arc::new(BoundedQueue::new(1));
+
+    let reader = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.read_timeout(Duration::from_secs(10)))
+    };
+
+    thread::sleep(Duration::from_millis(20));
+    queue.write_blocking('a');
+    assert_eq!(Ok('a'), reader.join().unwrap());
+}
+
+#[test]
+fn worker_pool_processes_every_job_once() {
+    const PRODUCERS: usize = 4;
+    const CONSUMERS: usize = 4;
+    const JOBS: usize = 1_000;
+    let queue = Arc::new(BoundedQueue::new(8));
+
+    let producers = (0..PRODUCERS)
+        .map(|p| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for job in 0..JOBS {
+                    queue.write_blocking(Some(p * JOBS + job));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let consumers = (0..CONSUMERS)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                let mut done = Vec::new();
+                while let Some(job) = queue.read_blocking() {
+                    done.push(job);
+                }
+                done
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    // one stop signal per consumer
+    for _ in 0..CONSUMERS {
+        queue.write_blocking(None);
+    }
+
+    let mut done = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect::<Vec<_>>();
+    done.sort_unstable();
+    assert_eq!(done, (0..PRODUCERS * JOBS).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "queue without capacity")]
+fn queue_without_capacity_is_rejected() {
+    BoundedQueue::<u8>::new(0);
+}
+
+#[test]
+#[should_panic(expected = "queue without capacity")]
+fn buffer_without_capacity_is_rejected() {
+    let _ = BoundedQueue::from(CircularBuffer::<u8>::new(0));
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::{CircularBuffer, Error, ShrinkPolicy};
+use std::{
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+mod common;
+
+use common::{contents, wrapped};
+
+#[test]
+fn reserve_grows_and_keeps_the_order() {
+    let mut buffer = wrapped();
+    buffer.reserve(1);
+    assert_eq!(buffer.capacity(), 4);
+
+    buffer.reserve(3);
+    assert_eq!(buffer.capacity(), 6);
+    assert_eq!(contents(&buffer), [3, 4, 5]);
+    for i in 6..9 {
+        assert!(buffer.write(i).is_ok());
+    }
+    assert_eq!(Err(Error::FullBuffer), buffer.write(9));
+    assert_eq!(contents(&buffer), [3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn shrink_to_never_drops_elements() {
+    let mut buffer = wrapped();
+    buffer.shrink_to(0);
+    assert_eq!(buffer.capacity(), 3);
+    assert!(buffer.is_full());
+    assert_eq!(contents(&buffer), [3, 4, 5]);
+
+    buffer.shrink_to(10);
+    assert_eq!(buffer.capacity(), 3);
+}
+
+#[test]
+fn resize_keeps_the_order() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.resize(8), Ok(()));
+    assert_eq!(buffer.capacity(), 8);
+    assert_eq!(contents(&buffer), [3, 4, 5]);
+
+    assert_eq!(buffer.resize(3), Ok(()));
+    assert_eq!(buffer.capacity(), 3);
+    assert_eq!(buffer.overwrite(6), Some(3));
+    assert_eq!(contents(&buffer), [4, 5, 6]);
+}
+
+#[test]
+fn resize_below_the_length_is_rejected_by_default() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.resize(2), Err(Error::FullBuffer));
+    assert_eq!(buffer.capacity(), 4);
+    assert_eq!(contents(&buffer), [3, 4, 5]);
+}
+
+#[test]
+fn resize_below_the_length_can_evict_the_oldest() {
+    let evicted = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&evicted);
+    let mut buffer = CircularBuffer::new(4)
+        .with_shrink_policy(ShrinkPolicy::EvictOldest)
+        .with_eviction_hook(move |_: &i32| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+    for i in 0..4 {
+        buffer.write(i).unwrap();
+    }
+
+    assert_eq!(buffer.resize(1), Ok(()));
+    assert_eq!(contents(&buffer), [3]);
+    assert_eq!(evicted.load(Ordering::Relaxed), 3);
+
+    assert_eq!(buffer.resize(0), Ok(()));
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.capacity(), 0);
+}
+
+#[test]
+fn auto_grow_turns_full_writes_into_reallocation() {
+    let mut buffer = CircularBuffer::new(2).with_auto_grow();
+    for i in 0..5 {
+        assert!(buffer.write(i).is_ok());
+    }
+    assert_eq!(buffer.capacity(), 8);
+    assert_eq!(contents(&buffer), [0, 1, 2, 3, 4]);
+
+    let mut empty = CircularBuffer::new(0).with_auto_grow();
+    assert!(empty.write('1').is_ok());
+    assert_eq!(empty.read(), Ok('1'));
+}
+
+#[test]
+fn auto_grow_takes_whole_slices() {
+    let mut buffer = CircularBuffer::new(2).with_auto_grow();
+    assert_eq!(buffer.write_slice(&[1, 2, 3, 4, 5]), 5);
+    assert_eq!(buffer.capacity(), 5);
+    assert_eq!(contents(&buffer), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn overwrite_does_not_grow() {
+    let mut buffer = CircularBuffer::new(2).with_auto_grow();
+    buffer.overwrite(1);
+    buffer.overwrite(2);
+    assert_eq!(buffer.overwrite(3), Some(1));
+    assert_eq!(buffer.capacity(), 2);
+}
+
+#[test]
+fn relocated_elements_are_dropped_once() {
+    let element = Rc::new(());
+    let mut buffer = CircularBuffer::new(2).with_auto_grow();
+    for _ in 0..5 {
+        buffer.write(Rc::clone(&element)).unwrap();
+    }
+    buffer.shrink_to(0);
+    buffer.resize(16).unwrap();
+    assert_eq!(Rc::strong_count(&element), 6);
+    drop(buffer);
+    assert_eq!(Rc::strong_count(&element), 1);
+}
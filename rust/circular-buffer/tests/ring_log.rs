@@ -0,0 +1,233 @@
+#![cfg(feature = "std")]
+
+use circular_buffer::{Error, LogError, RingLog};
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+/// A log file of its own for every test, removed when the test ends.
+struct TempLog(PathBuf);
+
+impl TempLog {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "circular-buffer-{}-{}.log",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_file(&path);
+        TempLog(path)
+    }
+
+    fn open(&self, capacity: usize) -> RingLog {
+        RingLog::open(&self.0, capacity, 16).unwrap()
+    }
+}
+
+impl Drop for TempLog {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn records_are_read_in_the_order_they_are_written() {
+    let file = TempLog::new("order");
+    let mut log = file.open(2);
+    assert!(matches!(
+        log.read(),
+        Err(LogError::Buffer(Error::EmptyBuffer))
+    ));
+    log.write(b"one").unwrap();
+    log.write(b"").unwrap();
+    assert!(matches!(
+        log.write(b"three"),
+        Err(LogError::Buffer(Error::FullBuffer))
+    ));
+    assert_eq!(log.read().unwrap(), b"one");
+    assert_eq!(log.read().unwrap(), b"");
+    assert!(log.is_empty());
+}
+
+#[test]
+fn overwrite_returns_the_evicted_record() {
+    let file = TempLog::new("overwrite");
+    let mut log = file.open(2);
+    assert_eq!(log.overwrite(b"1").unwrap(), None);
+    assert_eq!(log.overwrite(b"2").unwrap(), None);
+    assert_eq!(log.overwrite(b"3").unwrap(), Some(b"1".to_vec()));
+    assert_eq!(log.read().unwrap(), b"2");
+    assert_eq!(log.read().unwrap(), b"3");
+}
+
+#[test]
+fn cursors_are_recovered_after_reopening() {
+    let file = TempLog::new("recover");
+    {
+        let mut log = file.open(3);
+        for record in ["a", "b", "c", "d", "e"] {
+            log.overwrite(record.as_bytes()).unwrap();
+        }
+        assert_eq!(log.read().unwrap(), b"c");
+    }
+
+    let mut log = file.open(3);
+    assert_eq!(log.len(), 2);
+    log.write(b"f").unwrap();
+    assert!(log.is_full());
+    assert_eq!(log.read().unwrap(), b"d");
+    assert_eq!(log.read().unwrap(), b"e");
+    assert_eq!(log.read().unwrap(), b"f");
+
+    drop(log);
+    let mut log = file.open(3);
+    assert!(log.is_empty());
+    log.write(b"g").unwrap();
+    drop(log);
+    assert_eq!(file.open(3).read().unwrap(), b"g");
+}
+
+#[test]
+fn a_torn_record_is_dropped_on_recovery() {
+    let file = TempLog::new("torn");
+    {
+        let mut log = file.open(4);
+        for record in ["a", "b", "c"] {
+            log.write(record.as_bytes()).unwrap();
+        }
+    }
+
+    // flip a byte of the newest record, as if the crash hit in the middle of writing it
+    let mut raw = OpenOptions::new().write(true).open(&file.0).unwrap();
+    raw.seek(SeekFrom::Start(16 + 2 * (16 + 16) + 16)).unwrap();
+    raw.write_all(b"x").unwrap();
+    drop(raw);
+
+    let mut log = file.open(4);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.read().unwrap(), b"a");
+    assert_eq!(log.read().unwrap(), b"b");
+    assert!(log.read().is_err());
+}
+
+#[test]
+fn records_longer_than_the_slots_are_rejected() {
+    let file = TempLog::new("too-long");
+    let mut log = file.open(2);
+    assert!(matches!(
+        log.write(&[0; 17]),
+        Err(LogError::RecordTooLong {
+            len: 17,
+            max_record_len: 16
+        })
+    ));
+    assert!(log.is_empty());
+}
+
+#[test]
+fn a_corrupted_record_is_skipped_when_reading() {
+    let file = TempLog::new("corrupted");
+    let mut log = file.open(4);
+    for record in ["a", "b", "c"] {
+        log.write(record.as_bytes()).unwrap();
+    }
+
+    // flip a byte of the oldest record, after the cursors were recovered
+    let mut raw = OpenOptions::new().write(true).open(&file.0).unwrap();
+    raw.seek(SeekFrom::Start(16 + 16)).unwrap();
+    raw.write_all(b"x").unwrap();
+    drop(raw);
+
+    assert_eq!(log.read().unwrap(), b"b");
+    assert_eq!(log.corrupted(), 1);
+    assert_eq!(log.read().unwrap(), b"c");
+    assert!(log.is_empty());
+}
+
+#[test]
+fn records_before_a_corrupted_one_are_dropped_on_recovery() {
+    let file = TempLog::new("gap");
+    {
+        let mut log = file.open(4);
+        for record in ["r0", "r1", "r2", "r3"] {
+            log.write(record.as_bytes()).unwrap();
+        }
+    }
+
+    let mut raw = OpenOptions::new().write(true).open(&file.0).unwrap();
+    raw.seek(SeekFrom::Start(16 + 2 * (16 + 16) + 16)).unwrap();
+    raw.write_all(b"x").unwrap();
+    drop(raw);
+
+    let mut log = file.open(4);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log.corrupted(), 2);
+    assert_eq!(log.read().unwrap(), b"r3");
+    drop(log);
+
+    // the older records don't come back after the newer one was read
+    let mut log = file.open(4);
+    assert!(log.is_empty());
+    assert_eq!(log.corrupted(), 0);
+    log.write(b"r4").unwrap();
+    assert_eq!(log.read().unwrap(), b"r4");
+}
+
+#[test]
+fn a_log_whose_header_never_made_it_to_disk_is_new() {
+    let file = TempLog::new("no-header");
+    // a crash after creating the file, or after allocating it but before the header
+    fs::write(&file.0, []).unwrap();
+    drop(file.open(2));
+    fs::write(&file.0, [0; 100]).unwrap();
+
+    let mut log = file.open(2);
+    assert!(log.is_empty());
+    log.write(b"a").unwrap();
+    drop(log);
+    assert_eq!(file.open(2).read().unwrap(), b"a");
+}
+
+#[test]
+fn missing_slots_of_a_log_are_empty() {
+    let file = TempLog::new("no-slots");
+    drop(file.open(2));
+    // a crash after the header was synced, before the slots were allocated
+    OpenOptions::new()
+        .write(true)
+        .open(&file.0)
+        .unwrap()
+        .set_len(16)
+        .unwrap();
+
+    let mut log = file.open(2);
+    assert!(log.is_empty());
+    log.write(b"a").unwrap();
+    log.write(b"b").unwrap();
+    assert_eq!(log.read().unwrap(), b"a");
+}
+
+#[test]
+fn reopening_with_another_layout_fails() {
+    let file = TempLog::new("layout");
+    drop(file.open(2));
+    match RingLog::open(&file.0, 3, 16) {
+        Err(LogError::Io(err)) => assert_eq!(err.kind(), ErrorKind::InvalidData),
+        _ => panic!("opened with another layout"),
+    }
+}
+
+#[test]
+fn errors_display() {
+    assert_eq!(LogError::from(Error::FullBuffer).to_string(), "log is full");
+    assert_eq!(
+        LogError::RecordTooLong {
+            len: 17,
+            max_record_len: 16
+        }
+        .to_string(),
+        "record of 17 bytes, at most 16 fit"
+    );
+}
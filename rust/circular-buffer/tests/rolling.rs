@@ -0,0 +1,133 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::RollingWindow;
+
+/// Statistics recomputed from scratch, to check the incremental ones against.
+fn naive(values: &[f64]) -> (f64, f64, f64, f64, f64) {
+    let n = values.len() as f64;
+    let sum = values.iter().sum::<f64>();
+    let mean = sum / n;
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    (sum, mean, variance, min, max)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn empty_window_has_no_statistics() {
+    let window = RollingWindow::<f64>::new(3);
+    assert_eq!(window.sum(), 0.0);
+    assert_eq!(window.mean(), None);
+    assert_eq!(window.variance(), None);
+    assert_eq!(window.min(), None);
+    assert_eq!(window.max(), None);
+}
+
+#[test]
+fn statistics_follow_the_window() {
+    let mut window = RollingWindow::new(3);
+    assert_eq!(window.push(2), None);
+    assert_eq!(window.push(4), None);
+    assert_eq!(window.push(9), None);
+    assert_eq!(window.sum(), 15.0);
+    assert_eq!(window.mean(), Some(5.0));
+    assert_close(window.variance().unwrap(), 26.0 / 3.0);
+    assert_eq!(window.min(), Some(2));
+    assert_eq!(window.max(), Some(9));
+
+    assert_eq!(window.push(3), Some(2));
+    assert_eq!(window.sum(), 16.0);
+    assert_eq!(window.min(), Some(3));
+    assert_eq!(window.max(), Some(9));
+
+    window.push(1);
+    window.push(1);
+    assert_eq!(
+        window.values().iter().copied().collect::<Vec<_>>(),
+        [3, 1, 1]
+    );
+    assert_eq!(window.min(), Some(1));
+    assert_eq!(window.max(), Some(3));
+    assert_close(window.variance().unwrap(), 8.0 / 9.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_dev_is_the_root_of_the_variance() {
+    let mut window = RollingWindow::new(3);
+    assert_eq!(window.std_dev(), None);
+    for x in [3, 1, 1] {
+        window.push(x);
+    }
+    assert_close(window.std_dev().unwrap(), (8.0f64 / 9.0).sqrt());
+}
+
+#[test]
+fn single_value_window() {
+    let mut window = RollingWindow::new(1);
+    for x in [5.0, -1.0, 3.5] {
+        window.push(x);
+        assert_eq!(window.mean(), Some(x));
+        assert_eq!(window.variance(), Some(0.0));
+        assert_eq!(window.min(), Some(x));
+        assert_eq!(window.max(), Some(x));
+    }
+}
+
+#[test]
+fn matches_recomputation_over_a_long_run() {
+    let mut window = RollingWindow::new(7);
+    let mut all = Vec::new();
+    let mut seed = 12345u32;
+    for _ in 0..10_000 {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        let x = f64::from(seed >> 16) / 100.0 - 100.0;
+        window.push(x);
+        all.push(x);
+
+        let recent = &all[all.len().saturating_sub(7)..];
+        let (sum, mean, variance, min, max) = naive(recent);
+        assert_close(window.sum(), sum);
+        assert_close(window.mean().unwrap(), mean);
+        assert_close(window.variance().unwrap(), variance);
+        assert_eq!(window.min(), Some(min));
+        assert_eq!(window.max(), Some(max));
+    }
+}
+
+#[test]
+fn statistics_recover_after_an_outlier_leaves() {
+    for outlier in [1e12, 1e17, -1e300, f64::NAN] {
+        let mut window = RollingWindow::new(3);
+        for x in [outlier, 1.0, 2.0, 3.0] {
+            window.push(x);
+        }
+        assert_eq!(window.sum(), 6.0);
+        assert_close(window.mean().unwrap(), 2.0);
+        assert_close(window.variance().unwrap(), 2.0 / 3.0);
+
+        window.push(4.0);
+        assert_eq!(window.sum(), 9.0);
+        assert_close(window.variance().unwrap(), 2.0 / 3.0);
+    }
+}
+
+#[test]
+fn min_and_max_skip_nan() {
+    let mut window = RollingWindow::new(3);
+    window.push(1.0);
+    window.push(f64::NAN);
+    window.push(2.0);
+    assert_eq!(window.min(), Some(1.0));
+    assert_eq!(window.max(), Some(2.0));
+    assert!(window.mean().unwrap().is_nan());
+}
@@ -0,0 +1,101 @@
+#![cfg(all(feature = "alloc", not(loom)))]
+
+use circular_buffer::{CircularBuffer, Error};
+use std::{rc::Rc, thread};
+
+#[test]
+fn split_keeps_existing_elements() {
+    let mut buffer = CircularBuffer::new(3);
+    buffer.write('1').unwrap();
+    buffer.write('2').unwrap();
+    let (mut producer, mut consumer) = buffer.split();
+
+    assert_eq!(producer.len(), 2);
+    assert!(producer.write('3').is_ok());
+    assert_eq!(Err(Error::FullBuffer), producer.write('4'));
+    assert_eq!(Ok('1'), consumer.read());
+    assert_eq!(Ok('2'), consumer.read());
+    assert_eq!(Ok('3'), consumer.read());
+    assert_eq!(Err(Error::EmptyBuffer), consumer.read());
+}
+
+#[test]
+fn read_frees_up_capacity_for_another_write() {
+    let (mut producer, mut consumer) = CircularBuffer::new(1).split();
+    assert!(!producer.is_full() && consumer.is_empty());
+    assert!(producer.write(1).is_ok());
+    assert!(producer.is_full());
+    assert_eq!(Ok(1), consumer.read());
+    assert!(producer.write(2).is_ok());
+    assert_eq!(Ok(2), consumer.read());
+    assert_eq!(consumer.capacity(), 1);
+}
+
+#[test]
+fn remaining_elements_are_dropped() {
+    let element = Rc::new(());
+    let (mut producer, consumer) = CircularBuffer::new(2).split();
+    producer.write(Rc::clone(&element)).unwrap();
+    producer.write(Rc::clone(&element)).unwrap();
+    assert_eq!(Rc::strong_count(&element), 3);
+
+    drop(producer);
+    assert_eq!(Rc::strong_count(&element), 3);
+    drop(consumer);
+    assert_eq!(Rc::strong_count(&element), 1);
+}
+
+#[test]
+fn elements_cross_threads_in_order() {
+    const COUNT: usize = 100_000;
+    let (mut producer, mut consumer) = CircularBuffer::new(16).split();
+
+    let writer = thread::spawn(move || {
+        for i in 0..COUNT {
+            while producer.write(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    let mut expected = 0;
+    while expected < COUNT {
+        match consumer.read() {
+            Ok(value) => {
+                assert_eq!(value, expected);
+                expected += 1;
+            }
+            Err(Error::EmptyBuffer) => thread::yield_now(),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    writer.join().unwrap();
+}
+
+#[test]
+fn zero_capacity_split() {
+    let (mut producer, mut consumer) = CircularBuffer::new(0).split();
+    assert!(producer.is_full() && consumer.is_empty());
+    assert_eq!(Err(Error::FullBuffer), producer.write('1'));
+    assert_eq!(Err(Error::EmptyBuffer), consumer.read());
+}
+
+#[test]
+fn cursors_wrap_around_with_any_capacity() {
+    let (mut producer, mut consumer) = CircularBuffer::new(3).split();
+    for round in 0..20 {
+        for i in 0..3 {
+            producer.write(round * 3 + i).unwrap();
+        }
+        assert!(producer.is_full());
+        assert_eq!(Err(Error::FullBuffer), producer.write(-1));
+        assert_eq!(consumer.len(), 3);
+        assert_eq!(Ok(round * 3), consumer.read());
+        producer.write(-1).unwrap();
+        assert_eq!(Ok(round * 3 + 1), consumer.read());
+        assert_eq!(Ok(round * 3 + 2), consumer.read());
+        assert_eq!(Ok(-1), consumer.read());
+        assert!(consumer.is_empty());
+    }
+}
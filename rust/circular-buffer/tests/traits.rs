@@ -0,0 +1,98 @@
+#![cfg(feature = "alloc")]
+
+use circular_buffer::{array, CircularBuffer};
+
+mod common;
+
+use common::wrapped;
+
+#[test]
+fn debug_shows_the_logical_order() {
+    assert_eq!(format!("{:?}", wrapped()), "[3, 4, 5]");
+    assert_eq!(format!("{:?}", CircularBuffer::<u8>::new(2)), "[]");
+
+    let mut buffer = array::CircularBuffer::<_, 2>::new();
+    buffer.extend(['a', 'b', 'c']);
+    assert_eq!(format!("{:?}", buffer), "['b', 'c']");
+}
+
+#[test]
+fn clone_keeps_elements_and_capacity() {
+    let buffer = wrapped();
+    let mut clone = buffer.clone();
+    assert_eq!(clone, buffer);
+    assert_eq!(clone.capacity(), 4);
+    clone.write(6).unwrap();
+    assert_eq!(clone.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 6]);
+    assert_eq!(buffer.len(), 3);
+
+    let mut array = array::CircularBuffer::<String, 2>::new();
+    array.write("a".to_string()).unwrap();
+    assert_eq!(array.clone(), array);
+}
+
+#[test]
+fn equality_compares_the_logical_contents() {
+    let mut other = CircularBuffer::new(3);
+    for i in 3..=5 {
+        other.write(i).unwrap();
+    }
+    assert_eq!(wrapped(), other);
+
+    other.write(6).unwrap_err();
+    other.read().unwrap();
+    assert_ne!(wrapped(), other);
+
+    // the capacity isn't part of the contents
+    let mut larger = CircularBuffer::new(10);
+    larger.extend([3, 4, 5]);
+    assert_eq!(wrapped(), larger);
+}
+
+#[test]
+fn collect_into_a_full_buffer() {
+    let buffer = (1..=3).collect::<CircularBuffer<_>>();
+    assert_eq!(buffer.capacity(), 3);
+    assert!(buffer.is_full());
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn extend_keeps_the_newest_elements() {
+    let mut buffer = wrapped();
+    buffer.extend([6, 7]);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [4, 5, 6, 7]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips_contents_and_capacity() {
+    let json = serde_json::to_string(&wrapped()).unwrap();
+    assert_eq!(json, r#"{"capacity":4,"elements":[3,4,5]}"#);
+
+    let buffer: CircularBuffer<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(buffer, wrapped());
+    assert_eq!(buffer.capacity(), 4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_rejects_more_elements_than_capacity() {
+    let err = serde_json::from_str::<CircularBuffer<i32>>(r#"{"capacity":1,"elements":[1,2]}"#)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("2 elements don't fit a capacity of 1"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_rejects_capacities_that_cant_be_allocated() {
+    for capacity in [1_000_000_000_000_000, usize::MAX] {
+        let json = format!(r#"{{"capacity":{},"elements":[]}}"#, capacity);
+        let err = serde_json::from_str::<CircularBuffer<i32>>(&json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("can't allocate a capacity of {}", capacity)));
+    }
+}
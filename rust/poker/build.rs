@@ -0,0 +1,14 @@
+fn main() {
+    #[cfg(feature = "capi")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/capi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+
+        // tests/capi.rs checks include/poker.h against this copy
+        cbindgen::generate(&crate_dir)
+            .expect("unable to generate C bindings")
+            .write_to_file(format!("{}/poker.h", out_dir));
+    }
+}
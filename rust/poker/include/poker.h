@@ -0,0 +1,67 @@
+#ifndef POKER_H
+#define POKER_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct PokerHand PokerHand;
+
+/*
+ Category and tie-breaking ranks of an evaluated hand.
+ */
+typedef struct PokerEvaluation {
+  /*
+   0 = high card, 1 = one pair, ... 8 = straight flush.
+   */
+  uint8_t category;
+  /*
+   Card ranks (2 to 14 for an ace) in the order they decide ties: grouped cards first,
+   larger groups and higher ranks before the rest. An ace-low straight ranks its ace 1.
+   */
+  uint8_t kickers[5];
+} PokerEvaluation;
+
+/*
+ Parse a hand of five space separated cards like `"10S JH QH KH AH"`.
+
+ Returns NULL when the string is not a valid hand. The hand must be released with
+ `poker_hand_free`.
+
+ # Safety
+
+ `hand` must be NULL or a valid NUL-terminated string.
+ */
+struct PokerHand *poker_hand_parse(const char *hand);
+
+/*
+ Release a hand returned by `poker_hand_parse`. NULL is ignored.
+
+ # Safety
+
+ `hand` must be NULL or a hand from `poker_hand_parse` that was not released yet.
+ */
+void poker_hand_free(struct PokerHand *hand);
+
+/*
+ Evaluate a hand into `out`. Returns 0 on success, -1 when an argument is NULL.
+
+ # Safety
+
+ `hand` must be NULL or a live hand from `poker_hand_parse`, `out` NULL or writable.
+ */
+int poker_hand_evaluate(const struct PokerHand *hand, struct PokerEvaluation *out);
+
+/*
+ Compare two hands: 1 when `a` wins, -1 when `b` wins, 0 on a tie or a NULL argument.
+
+ # Safety
+
+ `a` and `b` must be NULL or live hands from `poker_hand_parse`.
+ */
+int poker_hand_compare(const struct PokerHand *a, const struct PokerHand *b);
+
+#endif  /* POKER_H */
@@ -0,0 +1,329 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use crate::{Card, CardSymbol, HandType, PokerHand};
+
+/// What a player can do when it's their turn.
+///
+/// Betting is fixed-limit without raises: the first bet of a round can only be called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet,
+}
+
+/// Read-only view of a hand in progress, as seen by the player to act.
+#[derive(Debug)]
+pub struct HandView<'a> {
+    pub hole_cards: &'a [Card],
+    // 0, 3, 4 or 5 community cards
+    pub board: &'a [Card],
+    pub pot: u64,
+    // chips needed to stay in the hand, 0 when nobody bet this round
+    pub to_call: u64,
+    pub legal_actions: &'a [Action],
+    // players that haven't folded, including the player to act
+    pub active_players: usize,
+}
+
+pub trait Strategy {
+    /// Pick one of `view.legal_actions`.
+    ///
+    /// An illegal action checks when possible and folds otherwise.
+    fn act(&mut self, view: &HandView) -> Action;
+}
+
+/// Never folds and never bets: checks or calls everything.
+#[derive(Debug, Default)]
+pub struct AlwaysCall;
+
+impl Strategy for AlwaysCall {
+    fn act(&mut self, view: &HandView) -> Action {
+        if view.legal_actions.contains(&Action::Call) {
+            Action::Call
+        } else {
+            Action::Check
+        }
+    }
+}
+
+/// Bets and calls with a made hand at least as strong as `min`, checks or folds otherwise.
+///
+/// Before the flop there is no made hand yet, so it checks and calls to see the flop.
+#[derive(Debug)]
+pub struct Threshold {
+    pub min: HandType,
+}
+
+impl Strategy for Threshold {
+    fn act(&mut self, view: &HandView) -> Action {
+        let cards = [view.hole_cards, view.board].concat();
+        let strong = match PokerHand::best_of(&cards) {
+            None => return AlwaysCall.act(view),
+            Some(hand) => hand.result >= self.min,
+        };
+
+        match (strong, view.to_call) {
+            (true, 0) => Action::Bet,
+            (true, _) => Action::Call,
+            (false, 0) => Action::Check,
+            (false, _) => Action::Fold,
+        }
+    }
+}
+
+impl PokerHand {
+    /// Build a hand from exactly five cards.
+    pub(crate) fn from_cards(cards: Vec<Card>) -> PokerHand {
+        let result = Self::determine_hand(&cards);
+        let origin = cards
+            .iter()
+            .map(Card::canonical)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        PokerHand {
+            cards,
+            result,
+            origin,
+            rules: Default::default(),
+        }
+    }
+
+    /// The best five-card hand out of five to seven cards, `None` with fewer than five.
+    pub fn best_of(cards: &[Card]) -> Option<PokerHand> {
+        let mut best: Option<PokerHand> = None;
+
+        for combination in combinations(cards.len(), 5) {
+            let hand =
+                PokerHand::from_cards(combination.iter().map(|&i| cards[i].clone()).collect());
+            if best
+                .as_ref()
+                .is_none_or(|b| hand.partial_cmp(b) == Some(Ordering::Greater))
+            {
+                best = Some(hand);
+            }
+        }
+
+        best
+    }
+}
+
+/// All ways to pick `k` out of `n` indices, in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k > n {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    let mut indices = (0..k).collect::<Vec<_>>();
+    loop {
+        result.push(indices.clone());
+
+        // find the rightmost index that can still move right
+        let Some(i) = (0..k).rev().find(|&i| indices[i] != i + n - k) else {
+            return result;
+        };
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+    }
+}
+
+/// SplitMix64: small, seedable and good enough to shuffle cards.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+fn shuffled_deck(rng: &mut Rng) -> Vec<Card> {
+    let symbols = [
+        CardSymbol::Club,
+        CardSymbol::Spade,
+        CardSymbol::Heart,
+        CardSymbol::Diamond,
+    ];
+    let mut deck = symbols
+        .iter()
+        .flat_map(|&symbol| (1..=13).map(move |number| Card { number, symbol }))
+        .collect::<Vec<_>>();
+
+    // Fisher-Yates
+    for i in (1..deck.len()).rev() {
+        deck.swap(i, rng.below(i + 1));
+    }
+
+    deck
+}
+
+/// Outcome of [`self_play`].
+#[derive(Debug, PartialEq)]
+pub struct SelfPlayReport {
+    pub hands: usize,
+    // pots won per player, a split pot counts as a fraction
+    pub wins: Vec<f64>,
+    // net chips won (or lost) per player
+    pub chips: Vec<i64>,
+}
+
+impl SelfPlayReport {
+    /// Share of all hands won by the player.
+    pub fn win_rate(&self, player: usize) -> f64 {
+        if self.hands == 0 {
+            0.0
+        } else {
+            self.wins[player] / self.hands as f64
+        }
+    }
+}
+
+/// Chips every player puts in before the cards are dealt.
+const ANTE: u64 = 1;
+/// Fixed size of a bet, doubled on the turn and river.
+const SMALL_BET: u64 = 2;
+
+/// Play `hands` hands of fixed-limit hold'em between the strategies.
+///
+/// The same seed deals the same cards, so a run can be reproduced exactly. The player
+/// acting first rotates every hand.
+///
+/// # Panics
+///
+/// With fewer than two or more than 23 players: a deck deals at most 23 hold'em hands.
+pub fn self_play(players: &mut [&mut dyn Strategy], hands: usize, seed: u64) -> SelfPlayReport {
+    assert!(
+        (2..=23).contains(&players.len()),
+        "self-play needs 2 to 23 players"
+    );
+
+    let mut rng = Rng(seed);
+    let mut report = SelfPlayReport {
+        hands,
+        wins: vec![0.0; players.len()],
+        chips: vec![0; players.len()],
+    };
+
+    for hand in 0..hands {
+        let first = hand % players.len();
+        play_hand(players, first, &mut rng, &mut report);
+    }
+
+    report
+}
+
+fn play_hand(
+    players: &mut [&mut dyn Strategy],
+    first: usize,
+    rng: &mut Rng,
+    report: &mut SelfPlayReport,
+) {
+    let count = players.len();
+    let mut deck = shuffled_deck(rng);
+    let hole_cards = (0..count)
+        .map(|_| deck.split_off(deck.len() - 2))
+        .collect::<Vec<_>>();
+    let full_board = deck.split_off(deck.len() - 5);
+
+    let mut active = vec![true; count];
+    let mut invested = vec![ANTE; count];
+
+    for (board_size, bet) in [
+        (0, SMALL_BET),
+        (3, SMALL_BET),
+        (4, 2 * SMALL_BET),
+        (5, 2 * SMALL_BET),
+    ] {
+        let board = &full_board[..board_size];
+        let seats_after = |seat: usize, active: &[bool]| {
+            (1..=count)
+                .map(|n| (seat + n) % count)
+                .filter(|&s| active[s])
+                .collect::<VecDeque<_>>()
+        };
+
+        // everybody acts once; after a bet everybody else has to respond to it
+        let mut to_act = seats_after(first + count - 1, &active);
+        let mut bet_to_match = None;
+        while let Some(seat) = to_act.pop_front() {
+            let active_players = active.iter().filter(|&&a| a).count();
+            if active_players < 2 {
+                break;
+            }
+
+            let to_call = bet_to_match.map_or(0, |target| target - invested[seat]);
+            let legal_actions: &[Action] = match bet_to_match {
+                None => &[Action::Check, Action::Bet],
+                Some(_) => &[Action::Fold, Action::Call],
+            };
+            let view = HandView {
+                hole_cards: &hole_cards[seat],
+                board,
+                pot: invested.iter().sum(),
+                to_call,
+                legal_actions,
+                active_players,
+            };
+
+            let action = players[seat].act(&view);
+            let action = if legal_actions.contains(&action) {
+                action
+            } else {
+                legal_actions[0]
+            };
+
+            match action {
+                Action::Fold => active[seat] = false,
+                Action::Check => {}
+                Action::Call => invested[seat] += to_call,
+                Action::Bet => {
+                    invested[seat] += bet;
+                    bet_to_match = Some(invested[seat]);
+                    to_act = seats_after(seat, &active);
+                    to_act.pop_back();
+                }
+            }
+        }
+    }
+
+    // showdown among the players left, or the last player standing
+    let remaining = (0..count).filter(|&seat| active[seat]).collect::<Vec<_>>();
+    let best = remaining
+        .iter()
+        .map(|&seat| PokerHand::best_of(&[hole_cards[seat].as_slice(), &full_board].concat()))
+        .collect::<Vec<_>>();
+
+    let mut winners: Vec<usize> = Vec::new();
+    for (i, hand) in best.iter().enumerate() {
+        match winners.first().and_then(|&w| hand.partial_cmp(&best[w])) {
+            None => winners.push(i),
+            Some(Ordering::Greater) => winners = vec![i],
+            Some(Ordering::Equal) => winners.push(i),
+            Some(Ordering::Less) => {}
+        }
+    }
+
+    let pot = invested.iter().sum::<u64>();
+    let share = pot / winners.len() as u64;
+    let odd_chips = pot % winners.len() as u64;
+    for (chips, &spent) in report.chips.iter_mut().zip(&invested) {
+        *chips -= spent as i64;
+    }
+    for (n, &winner) in winners.iter().enumerate() {
+        let winner = remaining[winner];
+        report.chips[winner] += (share + u64::from((n as u64) < odd_chips)) as i64;
+        report.wins[winner] += 1.0 / winners.len() as f64;
+    }
+}
@@ -0,0 +1,269 @@
+use std::{cmp::Ordering, collections::HashMap, fmt};
+
+use crate::{
+    showdown::{checked_poker_hands, Location},
+    Notations, ShowdownError,
+};
+
+/// Most steps [`icm_equity`] takes before giving up. Every step hands a place to one
+/// player, after one set of players took the places before it.
+pub const MAX_ICM_STEPS: u64 = 1 << 25;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IcmError {
+    // spreading the prizes would take more than `MAX_ICM_STEPS` steps
+    TooManyPlaces { players: usize, places: usize },
+}
+
+impl fmt::Display for IcmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IcmError::TooManyPlaces { players, places } => write!(
+                f,
+                "too many ways to hand {} paid places to {} players",
+                places, players
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IcmError {}
+
+/// Prize equity of every player under the Independent Chip Model.
+///
+/// The chance of a player finishing first is their share of all chips; the next places
+/// are handed out the same way among the players that are left. Players without chips
+/// are already out: they only share the prizes the players with chips can't take.
+///
+/// Only the paid places are expanded, so many players with a handful of prizes stay cheap.
+/// The work still grows with the number of players to the power of the paid places, so
+/// an error is returned instead of taking more than [`MAX_ICM_STEPS`] steps.
+pub fn icm_equity(stacks: &[u64], payouts: &[f64]) -> Result<Vec<f64>, IcmError> {
+    let mut equity = vec![0.0; stacks.len()];
+    let alive = (0..stacks.len())
+        .filter(|&i| stacks[i] > 0)
+        .collect::<Vec<_>>();
+    let busted = (0..stacks.len())
+        .filter(|&i| stacks[i] == 0)
+        .collect::<Vec<_>>();
+    let total = alive.iter().map(|&i| stacks[i] as f64).sum::<f64>();
+    let places = payouts.len().min(alive.len());
+
+    if steps(alive.len(), places) > MAX_ICM_STEPS {
+        return Err(IcmError::TooManyPlaces {
+            players: alive.len(),
+            places,
+        });
+    }
+
+    // players that took the places so far (bit set over `alive`) => (probability, chips taken)
+    let words = alive.len().div_ceil(64);
+    let mut layer: HashMap<Vec<u64>, (f64, f64)> = HashMap::from([(vec![0; words], (1.0, 0.0))]);
+    for (place, &payout) in payouts[..places].iter().enumerate() {
+        // nobody takes the places after the last paid one
+        let last = place + 1 == places;
+        let mut next: HashMap<Vec<u64>, (f64, f64)> = HashMap::new();
+
+        for (taken_by, (probability, taken)) in layer {
+            let remaining = total - taken;
+            for (n, &i) in alive.iter().enumerate() {
+                if taken_by[n / 64] & (1 << (n % 64)) != 0 {
+                    continue;
+                }
+
+                let chips = stacks[i] as f64;
+                let place_probability = probability * chips / remaining;
+                equity[i] += place_probability * payout;
+
+                if !last {
+                    let mut key = taken_by.clone();
+                    key[n / 64] |= 1 << (n % 64);
+                    next.entry(key).or_insert((0.0, taken + chips)).0 += place_probability;
+                }
+            }
+        }
+
+        layer = next;
+    }
+
+    // busted players tie for the places after all players with chips
+    if !busted.is_empty() {
+        let shared = payouts
+            .iter()
+            .skip(alive.len())
+            .take(busted.len())
+            .sum::<f64>();
+        let share = shared / busted.len() as f64;
+        for i in busted {
+            equity[i] = share;
+        }
+    }
+
+    Ok(equity)
+}
+
+/// Steps [`icm_equity`] takes for `places` paid places among `players`: for every place,
+/// the sets of players that took the places before it, times the players left.
+fn steps(players: usize, places: usize) -> u64 {
+    let mut sets = 1u64;
+    let mut steps = 0u64;
+    for place in 0..places as u64 {
+        let left = players as u64 - place;
+        steps = steps.saturating_add(sets.saturating_mul(left));
+        // sets of `place + 1` players out of `players`
+        sets = sets.saturating_mul(left) / (place + 1);
+    }
+    steps
+}
+
+/// Chips and prize equity of every player after an all-in showdown.
+#[derive(Debug, PartialEq)]
+pub struct Settlement {
+    pub stacks: Vec<u64>,
+    pub equity: Vec<f64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SettleError {
+    // the hands don't make a valid showdown, locations are seats
+    Showdown(ShowdownError),
+    // the resulting stacks can't be valued
+    Icm(IcmError),
+}
+
+impl fmt::Display for SettleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SettleError::Showdown(err) => write!(f, "{}", err),
+            SettleError::Icm(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SettleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SettleError::Showdown(err) => Some(err),
+            SettleError::Icm(err) => Some(err),
+        }
+    }
+}
+
+impl From<ShowdownError> for SettleError {
+    fn from(err: ShowdownError) -> Self {
+        SettleError::Showdown(err)
+    }
+}
+
+impl From<IcmError> for SettleError {
+    fn from(err: IcmError) -> Self {
+        SettleError::Icm(err)
+    }
+}
+
+/// Settle a showdown where every player holding a hand is all-in.
+///
+/// `hands[i]` is the five-card hand of player `i`, or `None` when the player is not part
+/// of the showdown. The hands are validated like [`crate::checked_winning_hands`] does,
+/// so no card may be dealt twice. Main and side pots are built from the stacks, every
+/// pot is split among the best hands eligible for it, and odd chips go to the winners
+/// seated first. The resulting stacks are valued with [`icm_equity`].
+pub fn settle_all_in(
+    stacks: &[u64],
+    hands: &[Option<&str>],
+    payouts: &[f64],
+) -> Result<Settlement, SettleError> {
+    let seats = (0..hands.len())
+        .filter(|&i| hands[i].is_some())
+        .collect::<Vec<_>>();
+    let shown = seats.iter().filter_map(|&i| hands[i]).collect::<Vec<_>>();
+
+    let mut poker_hands = hands.iter().map(|_| None).collect::<Vec<_>>();
+    if !shown.is_empty() {
+        let checked = checked_poker_hands(&shown, Notations::STANDARD)
+            .map_err(|err| at_seats(err, &seats))?;
+        for (&seat, (poker_hand, _)) in seats.iter().zip(checked) {
+            poker_hands[seat] = Some(poker_hand);
+        }
+    }
+
+    let players = (0..stacks.len())
+        .filter(|&i| stacks[i] > 0 && poker_hands.get(i).is_some_and(Option::is_some))
+        .collect::<Vec<_>>();
+
+    let mut levels = players.iter().map(|&i| stacks[i]).collect::<Vec<_>>();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut settled = stacks.to_vec();
+    for &i in &players {
+        settled[i] = 0;
+    }
+
+    let mut previous_level = 0;
+    for level in levels {
+        let eligible = players
+            .iter()
+            .copied()
+            .filter(|&i| stacks[i] >= level)
+            .collect::<Vec<_>>();
+        let pot = (level - previous_level) * eligible.len() as u64;
+        previous_level = level;
+
+        let hand = |i: usize| poker_hands[i].as_ref().unwrap();
+        let mut winners: Vec<usize> = Vec::new();
+        for i in eligible {
+            match winners.first().and_then(|&w| hand(i).partial_cmp(hand(w))) {
+                None => winners.push(i),
+                Some(Ordering::Greater) => winners = vec![i],
+                Some(Ordering::Equal) => winners.push(i),
+                Some(Ordering::Less) => {}
+            }
+        }
+
+        let share = pot / winners.len() as u64;
+        let odd_chips = pot % winners.len() as u64;
+        for (n, &winner) in winners.iter().enumerate() {
+            settled[winner] += share + u64::from((n as u64) < odd_chips);
+        }
+    }
+
+    Ok(Settlement {
+        equity: icm_equity(&settled, payouts)?,
+        stacks: settled,
+    })
+}
+
+/// Point the locations of an error about the shown hands at the seats holding them.
+fn at_seats(err: ShowdownError, seats: &[usize]) -> ShowdownError {
+    let seat = |location| match location {
+        Location::Hand(index) => Location::Hand(seats[index]),
+        Location::Board => Location::Board,
+    };
+
+    match err {
+        ShowdownError::InvalidCard { location, card } => ShowdownError::InvalidCard {
+            location: seat(location),
+            card,
+        },
+        ShowdownError::WrongCardCount {
+            location,
+            expected,
+            found,
+        } => ShowdownError::WrongCardCount {
+            location: seat(location),
+            expected,
+            found,
+        },
+        ShowdownError::DuplicateCard {
+            card,
+            first,
+            second,
+        } => ShowdownError::DuplicateCard {
+            card,
+            first: seat(first),
+            second: seat(second),
+        },
+        ShowdownError::NoHands => ShowdownError::NoHands,
+    }
+}
@@ -1,12 +1,27 @@
-use std::{cmp::Ordering, fmt};
-
-/// Given a list of poker hands, return a list of those hands which win.
-///
-/// Note the type signature: this function should return _the same_ reference to
-/// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
-///
-
-#[derive(Eq, PartialEq, Debug)]
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+mod bot;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod icm;
+mod notation;
+mod ranking;
+mod rules;
+mod showdown;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use bot::{self_play, Action, AlwaysCall, HandView, SelfPlayReport, Strategy, Threshold};
+pub use icm::{icm_equity, settle_all_in, IcmError, SettleError, Settlement, MAX_ICM_STEPS};
+pub use notation::Notations;
+pub use ranking::{rank_hands, Decider, RankedHand};
+pub use rules::{winning_hands_with_rules, Rules};
+pub use showdown::{
+    checked_winning_hands, checked_winning_hands_with, validate_showdown, validate_showdown_with,
+    Location, ShowdownError,
+};
+
+#[derive(Eq, PartialEq, Debug, Clone)]
 pub enum HandType {
     // high card
     StraightFlush(u8),
@@ -28,6 +43,38 @@ pub enum HandType {
     HighCard(u8),
 }
 
+impl HandType {
+    /// Strength of the hand category alone, higher is better.
+    fn category(&self) -> u8 {
+        match self {
+            HandType::StraightFlush(_) => 8,
+            HandType::FourOfAKind(_, _) => 7,
+            HandType::FullHouse(_, _) => 6,
+            HandType::Flush(_) => 5,
+            HandType::Straight(_) => 4,
+            HandType::ThreeOfAKind(_, _) => 3,
+            HandType::TwoPair(_, _, _) => 2,
+            HandType::OnePair(_, _) => 1,
+            HandType::HighCard(_) => 0,
+        }
+    }
+
+    /// Name of the hand category, e.g. `"FullHouse"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HandType::StraightFlush(_) => "StraightFlush",
+            HandType::FourOfAKind(_, _) => "FourOfAKind",
+            HandType::FullHouse(_, _) => "FullHouse",
+            HandType::Flush(_) => "Flush",
+            HandType::Straight(_) => "Straight",
+            HandType::ThreeOfAKind(_, _) => "ThreeOfAKind",
+            HandType::TwoPair(_, _, _) => "TwoPair",
+            HandType::OnePair(_, _) => "OnePair",
+            HandType::HighCard(_) => "HighCard",
+        }
+    }
+}
+
 impl PartialOrd for HandType {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match self {
@@ -111,7 +158,7 @@ impl fmt::Display for HandType {
     }
 }
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum CardSymbol {
     Club,
     Spade,
@@ -134,6 +181,7 @@ pub struct PokerHand {
     pub cards: Vec<Card>,
     pub result: HandType,
     pub origin: String,
+    pub rules: Rules,
 }
 
 impl fmt::Display for PokerHand {
@@ -146,7 +194,7 @@ impl fmt::Display for PokerHand {
     }
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct Card {
     number: u8,
     symbol: CardSymbol,
@@ -158,51 +206,70 @@ impl fmt::Display for Card {
     }
 }
 
+/// A card string which does not describe one of the 52 cards of a standard deck.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseCardError {
+    pub card: String,
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid card: {:?}", self.card)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parse a card in [`Notations::STANDARD`], like `"4S"` or `"10H"`.
+    /// Both `"A"` and `"1"` denote an ace.
+    fn from_str(card: &str) -> Result<Self, Self::Err> {
+        Card::parse_with(card, Notations::STANDARD)
+    }
+}
+
 impl PokerHand {
     pub fn new(input_cards: &[&str]) -> PokerHand {
-        let mut cards: Vec<Card> = Vec::new();
-
-        for card in input_cards {
-            let (num_string, symbol_char) = match card.len() {
-                2 => (card[0..1].to_string(), card.chars().next_back().unwrap()),
-                3 => (card[0..2].to_string(), card.chars().next_back().unwrap()),
-                _ => panic!("Card should be 2 or 3 characters"),
-            };
-
-            let number = match &*num_string {
-                "J" => 11,
-                "Q" => 12,
-                "K" => 13,
-                "A" => 1,
-                _ => (num_string.to_string()).parse::<u8>().unwrap_or(0),
-            };
-
-            let symbol: CardSymbol = match symbol_char {
-                'C' => CardSymbol::Club,
-                'S' => CardSymbol::Spade,
-                'H' => CardSymbol::Heart,
-                'D' => CardSymbol::Diamond,
-                _ => CardSymbol::Heart,
-            };
-
-            cards.push(Card { number, symbol })
+        match Self::parse(input_cards, Notations::STANDARD) {
+            Ok(hand) => hand,
+            Err(err) => panic!("{}", err),
         }
+    }
 
-        for v in &cards {
-            println!("\t{}", v);
-        }
+    /// Build a hand from cards written in any of the given notations.
+    ///
+    /// # Panics
+    ///
+    /// With other than five cards; [`crate::checked_winning_hands`] checks that first.
+    pub fn parse(input_cards: &[&str], notations: Notations) -> Result<PokerHand, ParseCardError> {
+        let cards = input_cards
+            .iter()
+            .map(|card| Card::parse_with(card, notations))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let result = Self::determine_hand(&cards);
-        println!("{}", result);
 
-        Self {
+        Ok(Self {
             cards,
             result,
             origin: input_cards.join(" "),
-        }
+            rules: Rules::Standard,
+        })
+    }
+
+    /// The hand in standard notation, cards separated by spaces.
+    pub fn canonical(&self) -> String {
+        self.cards
+            .iter()
+            .map(Card::canonical)
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
-    fn determine_hand(cards: &[Card]) -> HandType {
+    /// Evaluate exactly five cards.
+    pub(crate) fn determine_hand(cards: &[Card]) -> HandType {
         let symbols = cards.iter().map(|c| &c.symbol).collect::<Vec<_>>();
         let first_symbol = &symbols[0];
         let all_equal_symbols = symbols.iter().all(|symbol| symbol == first_symbol);
@@ -217,7 +284,7 @@ impl PokerHand {
         let diff_numbers_ace_low = numbers
             .iter()
             .enumerate()
-            .map(|(i, &number)| (number as i16 - highest_number as i16 + i as i16) as i16)
+            .map(|(i, &number)| number as i16 - highest_number as i16 + i as i16)
             .collect::<Vec<_>>();
 
         // replace all 1's with 14
@@ -233,7 +300,7 @@ impl PokerHand {
         let diff_numbers_ace_high = numbers
             .iter()
             .enumerate()
-            .map(|(i, &number)| (number as i16 - highest_number as i16 + i as i16) as i16)
+            .map(|(i, &number)| number as i16 - highest_number as i16 + i as i16)
             .collect::<Vec<_>>();
 
         let is_straight = diff_numbers_ace_high.iter().all(|&n| n == 0)
@@ -386,10 +453,15 @@ impl PokerHand {
     }
 }
 
+/// Hands evaluated under different [`Rules`] aren't ordered.
 impl PartialOrd for PokerHand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let result_cmp = self.result.partial_cmp(&other.result);
-        if result_cmp == Some(Ordering::Equal) {
+        if self.rules != other.rules {
+            return None;
+        }
+
+        let result_cmp = self.result.cmp_with(&other.result, self.rules);
+        if result_cmp == Ordering::Equal {
             let mut numbers_self = self.cards.iter().map(|c| c.number).collect::<Vec<u8>>();
             numbers_self.sort_by(|a, b| b.cmp(a));
             let mut numbers_other = other.cards.iter().map(|c| c.number).collect::<Vec<u8>>();
@@ -397,27 +469,40 @@ impl PartialOrd for PokerHand {
 
             Some(numbers_self.cmp(&numbers_other))
         } else {
-            result_cmp
+            Some(result_cmp)
         }
     }
 }
 impl PartialEq for PokerHand {
     fn eq(&self, other: &Self) -> bool {
-        self.result == other.result
+        self.rules == other.rules && self.result == other.result
     }
 }
 
+/// Given a list of poker hands, return a list of those hands which win.
+///
+/// Note the type signature: this function should return _the same_ reference to
+/// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
+///
+/// Cards may repeat across hands, as when playing with multiple decks; use
+/// [`checked_winning_hands`] to reject that and to get errors instead of panics.
+///
+/// # Panics
+///
+/// On an invalid card or a hand without exactly five cards.
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Vec<&'a str> {
-    // split hands on spaces
-    let mut poker_hands: Vec<(PokerHand, &str)> = Vec::new();
-    hands.iter().for_each(|hand_str| {
-        let hand_arr = hand_str.split(' ').collect::<Vec<&str>>();
-        poker_hands.push((PokerHand::new(&hand_arr), *hand_str));
-    });
+    match showdown::parse_poker_hands(hands, Notations::STANDARD) {
+        Ok(poker_hands) => best_hands(poker_hands),
+        Err(err) => panic!("{}", err),
+    }
+}
 
+fn best_hands(mut poker_hands: Vec<(PokerHand, &str)>) -> Vec<&str> {
     poker_hands.sort_by(|(pk_a, _a), (pk_b, _b)| pk_b.partial_cmp(pk_a).unwrap());
 
-    let (first_winning_hand, _) = &poker_hands[0];
+    let Some((first_winning_hand, _)) = poker_hands.first() else {
+        return Vec::new();
+    };
 
     // Can't return these because poker_hands is borrowed here
     poker_hands
@@ -0,0 +1,110 @@
+use crate::{Card, CardSymbol, ParseCardError};
+
+/// Emoji variation selector that may follow a suit glyph, as in "♠️".
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+
+/// The card notations accepted when parsing cards.
+///
+/// Whatever the input notation, cards are written back in the canonical form of
+/// [`Card::canonical`]: `"10"` for ten and an uppercase suit letter, e.g. `"10S"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Notations {
+    // "T" is accepted for ten next to "10"
+    pub ten_as_t: bool,
+    // suit glyphs "♣♠♥♦" (and their outlined variants) instead of "CSHD"
+    pub unicode_suits: bool,
+    // ranks and suits may be lowercase
+    pub case_insensitive: bool,
+}
+
+impl Notations {
+    /// Only the notation used by [`crate::winning_hands`]: `"10H"`, `"AS"`, ...
+    pub const STANDARD: Notations = Notations {
+        ten_as_t: false,
+        unicode_suits: false,
+        case_insensitive: false,
+    };
+
+    /// Every supported notation: `"Ts"`, `"t♠"`, `"A♥"`, `"ah"`, ...
+    pub const ALL: Notations = Notations {
+        ten_as_t: true,
+        unicode_suits: true,
+        case_insensitive: true,
+    };
+}
+
+impl Default for Notations {
+    fn default() -> Self {
+        Notations::STANDARD
+    }
+}
+
+impl Card {
+    /// Parse a card written in any of the given notations.
+    pub fn parse_with(card: &str, notations: Notations) -> Result<Card, ParseCardError> {
+        let error = || ParseCardError {
+            card: card.to_string(),
+        };
+
+        let mut input = card;
+        if notations.unicode_suits {
+            input = input.strip_suffix(VARIATION_SELECTOR).unwrap_or(input);
+        }
+
+        let symbol_char = input.chars().next_back().ok_or_else(error)?;
+        let num_string = &input[..input.len() - symbol_char.len_utf8()];
+        let (num_string, symbol_char) = if notations.case_insensitive {
+            (
+                num_string.to_ascii_uppercase(),
+                symbol_char.to_ascii_uppercase(),
+            )
+        } else {
+            (num_string.to_string(), symbol_char)
+        };
+
+        let number = match &*num_string {
+            "J" => 11,
+            "Q" => 12,
+            "K" => 13,
+            "A" | "1" => 1,
+            "T" if notations.ten_as_t => 10,
+            _ => match num_string.parse::<u8>() {
+                Ok(n) if (2..=10).contains(&n) && !num_string.starts_with(['0', '+']) => n,
+                _ => return Err(error()),
+            },
+        };
+
+        let symbol = match symbol_char {
+            'C' => CardSymbol::Club,
+            'S' => CardSymbol::Spade,
+            'H' => CardSymbol::Heart,
+            'D' => CardSymbol::Diamond,
+            '♣' | '♧' if notations.unicode_suits => CardSymbol::Club,
+            '♠' | '♤' if notations.unicode_suits => CardSymbol::Spade,
+            '♥' | '♡' if notations.unicode_suits => CardSymbol::Heart,
+            '♦' | '♢' if notations.unicode_suits => CardSymbol::Diamond,
+            _ => return Err(error()),
+        };
+
+        Ok(Card { number, symbol })
+    }
+
+    /// The card in standard notation, e.g. `"10S"` or `"AH"`.
+    pub fn canonical(&self) -> String {
+        let number = match self.number {
+            1 | 14 => "A".to_string(),
+            11 => "J".to_string(),
+            12 => "Q".to_string(),
+            13 => "K".to_string(),
+            n => n.to_string(),
+        };
+        let symbol = match self.symbol {
+            CardSymbol::Club => 'C',
+            CardSymbol::Spade => 'S',
+            CardSymbol::Heart => 'H',
+            CardSymbol::Diamond => 'D',
+        };
+
+        format!("{}{}", number, symbol)
+    }
+}
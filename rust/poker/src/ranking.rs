@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+use crate::{showdown::checked_poker_hands, HandType, Notations, PokerHand, ShowdownError};
+
+/// What separates a hand from the hand ranked right below it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Decider {
+    // a better hand category, e.g. a flush over a straight
+    Category,
+    // same category, better ranks inside it, e.g. a pair of kings over a pair of queens
+    Rank,
+    // same ranks making up the category, decided by the other cards
+    Kicker,
+    // equally strong, the hands split
+    Tie,
+}
+
+/// A hand of [`rank_hands`] together with its evaluation.
+#[derive(Debug, PartialEq)]
+pub struct RankedHand<'a> {
+    pub hand: &'a str,
+    pub hand_type: HandType,
+    // 1-based finishing position, tied hands share the position (1, 1, 3, ...)
+    pub position: usize,
+    // compared to the next hand in the ranking, `None` for the last hand
+    pub decided_by: Option<Decider>,
+}
+
+/// Rank every hand, best hand first.
+///
+/// Tied hands keep their input order. Like [`crate::checked_winning_hands`], the hands
+/// are validated first, and the returned hands are the same references as were passed in.
+pub fn rank_hands<'a>(hands: &[&'a str]) -> Result<Vec<RankedHand<'a>>, ShowdownError> {
+    let mut poker_hands = checked_poker_hands(hands, Notations::STANDARD)?;
+
+    // stable sort: tied hands stay in input order
+    poker_hands.sort_by(|(pk_a, _a), (pk_b, _b)| pk_b.partial_cmp(pk_a).unwrap());
+
+    let mut ranking: Vec<RankedHand> = Vec::with_capacity(poker_hands.len());
+    for (index, (poker_hand, origin)) in poker_hands.iter().enumerate() {
+        let decided_by = poker_hands
+            .get(index + 1)
+            .map(|(next, _)| decider(poker_hand, next));
+
+        let position = match ranking.last() {
+            Some(previous) if previous.decided_by == Some(Decider::Tie) => previous.position,
+            _ => index + 1,
+        };
+
+        ranking.push(RankedHand {
+            hand: origin,
+            hand_type: poker_hand.result.clone(),
+            position,
+            decided_by,
+        });
+    }
+
+    Ok(ranking)
+}
+
+fn decider(hand: &PokerHand, next: &PokerHand) -> Decider {
+    if hand.partial_cmp(next) == Some(Ordering::Equal) {
+        Decider::Tie
+    } else if hand.result.category_with(hand.rules) != next.result.category_with(next.rules) {
+        Decider::Category
+    } else if defining_ranks(&hand.result) != defining_ranks(&next.result) {
+        Decider::Rank
+    } else {
+        Decider::Kicker
+    }
+}
+
+/// The ranks that make up the hand category, e.g. the pair of a pair or the top card of
+/// a straight. The other cards of the hand are kickers, a flush and a high card only
+/// have kickers.
+fn defining_ranks(hand_type: &HandType) -> (u8, u8) {
+    match *hand_type {
+        HandType::StraightFlush(high) | HandType::Straight(high) => (high, 0),
+        HandType::FourOfAKind(quads, _) => (quads, 0),
+        HandType::FullHouse(trips, pair) => (trips, pair),
+        HandType::ThreeOfAKind(trips, _) => (trips, 0),
+        HandType::TwoPair(high_pair, low_pair, _) => (high_pair, low_pair),
+        HandType::OnePair(pair, _) => (pair, 0),
+        HandType::Flush(_) | HandType::HighCard(_) => (0, 0),
+    }
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use crate::{
+    best_hands,
+    showdown::{checked_poker_hands, Location},
+    Card, HandType, Notations, PokerHand, ShowdownError,
+};
+
+/// The poker variant hands are evaluated and ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rules {
+    #[default]
+    Standard,
+    // six-plus hold'em: 2 to 5 are removed, a flush beats a full house
+    // and A-6-7-8-9 is the lowest straight
+    ShortDeck,
+}
+
+impl Rules {
+    /// Whether the card is part of the deck played with.
+    pub fn allows(&self, card: &Card) -> bool {
+        match self {
+            Rules::Standard => true,
+            Rules::ShortDeck => card.number == 1 || card.number >= 6,
+        }
+    }
+}
+
+impl HandType {
+    /// Strength of the hand category alone under the given rules, higher is better.
+    pub(crate) fn category_with(&self, rules: Rules) -> u8 {
+        match (rules, self) {
+            (Rules::ShortDeck, HandType::Flush(_)) => 6,
+            (Rules::ShortDeck, HandType::FullHouse(_, _)) => 5,
+            _ => self.category(),
+        }
+    }
+
+    /// Order two hand types under the given rules.
+    pub fn cmp_with(&self, other: &HandType, rules: Rules) -> Ordering {
+        match self.category_with(rules).cmp(&other.category_with(rules)) {
+            Ordering::Equal => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+            ordering => ordering,
+        }
+    }
+}
+
+impl PokerHand {
+    /// Evaluate exactly five cards under the given rules.
+    pub(crate) fn determine_hand_with(cards: &[Card], rules: Rules) -> HandType {
+        let result = Self::determine_hand(cards);
+
+        if rules == Rules::ShortDeck {
+            // A-6-7-8-9: the ace plays low, right below the six
+            let mut numbers = cards.iter().map(|c| c.number).collect::<Vec<_>>();
+            numbers.sort_unstable();
+            if numbers == [1, 6, 7, 8, 9] {
+                return match result {
+                    HandType::Flush(_) => HandType::StraightFlush(9),
+                    _ => HandType::Straight(9),
+                };
+            }
+        }
+
+        result
+    }
+
+    /// Re-evaluate the hand under the given rules.
+    pub fn with_rules(mut self, rules: Rules) -> PokerHand {
+        self.result = Self::determine_hand_with(&self.cards, rules);
+        self.rules = rules;
+        self
+    }
+}
+
+/// Like [`crate::checked_winning_hands`], with hands evaluated and ordered under the
+/// given rules. Cards missing from the variant's deck are invalid.
+pub fn winning_hands_with_rules<'a>(
+    hands: &[&'a str],
+    rules: Rules,
+) -> Result<Vec<&'a str>, ShowdownError> {
+    let mut poker_hands = Vec::new();
+    for (index, (poker_hand, hand_str)) in checked_poker_hands(hands, Notations::STANDARD)?
+        .into_iter()
+        .enumerate()
+    {
+        let outside_deck = poker_hand
+            .cards
+            .iter()
+            .zip(hand_str.split(' '))
+            .find(|(card, _)| !rules.allows(card));
+        if let Some((_, card_str)) = outside_deck {
+            return Err(ShowdownError::InvalidCard {
+                location: Location::Hand(index),
+                card: card_str.to_string(),
+            });
+        }
+
+        poker_hands.push((poker_hand.with_rules(rules), hand_str));
+    }
+
+    Ok(best_hands(poker_hands))
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{best_hands, Card, Notations, PokerHand};
+
+/// Fewest cards a player can be evaluated with.
+const MIN_CARDS: usize = 5;
+/// Most cards a player can hold together with the board (hold'em: 2 + 5).
+const MAX_CARDS: usize = 7;
+/// Largest board dealt in any supported variant.
+const MAX_BOARD: usize = 5;
+
+/// Where a card of a showdown was found.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Location {
+    // index into the hands passed to the validation
+    Hand(usize),
+    Board,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Location::Hand(index) => write!(f, "hand {}", index),
+            Location::Board => write!(f, "board"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ShowdownError {
+    // nothing to rank
+    NoHands,
+    // card string that is not part of a standard deck
+    InvalidCard {
+        location: Location,
+        card: String,
+    },
+    // hand or board holding the wrong number of cards
+    WrongCardCount {
+        location: Location,
+        expected: usize,
+        found: usize,
+    },
+    // the same card dealt twice
+    DuplicateCard {
+        card: String,
+        first: Location,
+        second: Location,
+    },
+}
+
+impl fmt::Display for ShowdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowdownError::NoHands => write!(f, "showdown without hands"),
+            ShowdownError::InvalidCard { location, card } => {
+                write!(f, "invalid card {:?} in {}", card, location)
+            }
+            ShowdownError::WrongCardCount {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} holds {} cards, expected {}",
+                location, found, expected
+            ),
+            ShowdownError::DuplicateCard {
+                card,
+                first,
+                second,
+            } => write!(f, "card {} dealt to both {} and {}", card, first, second),
+        }
+    }
+}
+
+impl std::error::Error for ShowdownError {}
+
+/// Check a whole showdown before ranking it.
+///
+/// Every card of every hand and of the (possibly empty) board has to be a valid card,
+/// no card may be dealt twice, all hands need the same number of cards and every hand
+/// together with the board has to make between five and seven cards.
+pub fn validate_showdown(hands: &[&str], board: &[&str]) -> Result<(), ShowdownError> {
+    validate_showdown_with(hands, board, Notations::STANDARD)
+}
+
+/// Like [`validate_showdown`], for cards written in any of the given notations.
+/// A card counts as duplicate whatever notation it was written in.
+pub fn validate_showdown_with(
+    hands: &[&str],
+    board: &[&str],
+    notations: Notations,
+) -> Result<(), ShowdownError> {
+    if hands.is_empty() {
+        return Err(ShowdownError::NoHands);
+    }
+
+    if board.len() > MAX_BOARD {
+        return Err(ShowdownError::WrongCardCount {
+            location: Location::Board,
+            expected: MAX_BOARD,
+            found: board.len(),
+        });
+    }
+
+    let mut seen: HashMap<Card, Location> = HashMap::new();
+    let mut check_card = |card_str: &str, location: Location| {
+        let card =
+            Card::parse_with(card_str, notations).map_err(|_| ShowdownError::InvalidCard {
+                location,
+                card: card_str.to_string(),
+            })?;
+
+        match seen.get(&card) {
+            Some(&first) => Err(ShowdownError::DuplicateCard {
+                card: card_str.to_string(),
+                first,
+                second: location,
+            }),
+            None => {
+                seen.insert(card, location);
+                Ok(())
+            }
+        }
+    };
+
+    for card in board {
+        check_card(card, Location::Board)?;
+    }
+
+    let mut expected = None;
+    for (index, hand) in hands.iter().enumerate() {
+        let location = Location::Hand(index);
+        let cards = hand.split(' ').collect::<Vec<_>>();
+
+        // the first hand decides how many cards every player holds
+        let expected_count = *expected.get_or_insert_with(|| {
+            cards.len().clamp(
+                MIN_CARDS.saturating_sub(board.len()),
+                MAX_CARDS - board.len(),
+            )
+        });
+        if cards.len() != expected_count {
+            return Err(ShowdownError::WrongCardCount {
+                location,
+                expected: expected_count,
+                found: cards.len(),
+            });
+        }
+
+        for card in cards {
+            check_card(card, location)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`crate::winning_hands`], but the hands are validated with [`validate_showdown`] first.
+pub fn checked_winning_hands<'a>(hands: &[&'a str]) -> Result<Vec<&'a str>, ShowdownError> {
+    checked_winning_hands_with(hands, Notations::STANDARD)
+}
+
+/// Like [`checked_winning_hands`], for cards written in any of the given notations.
+pub fn checked_winning_hands_with<'a>(
+    hands: &[&'a str],
+    notations: Notations,
+) -> Result<Vec<&'a str>, ShowdownError> {
+    Ok(best_hands(checked_poker_hands(hands, notations)?))
+}
+
+/// Validate and evaluate five-card hands, without panicking on any input.
+pub(crate) fn checked_poker_hands<'a>(
+    hands: &[&'a str],
+    notations: Notations,
+) -> Result<Vec<(PokerHand, &'a str)>, ShowdownError> {
+    validate_showdown_with(hands, &[], notations)?;
+    parse_poker_hands(hands, notations)
+}
+
+/// Evaluate five-card hands, checking every card but allowing it to be dealt twice.
+pub(crate) fn parse_poker_hands<'a>(
+    hands: &[&'a str],
+    notations: Notations,
+) -> Result<Vec<(PokerHand, &'a str)>, ShowdownError> {
+    let mut poker_hands = Vec::new();
+    for (index, hand) in hands.iter().enumerate() {
+        let cards = hand.split(' ').collect::<Vec<_>>();
+
+        // `determine_hand` evaluates exactly five cards per hand
+        if cards.len() != MIN_CARDS {
+            return Err(ShowdownError::WrongCardCount {
+                location: Location::Hand(index),
+                expected: MIN_CARDS,
+                found: cards.len(),
+            });
+        }
+
+        let poker_hand =
+            PokerHand::parse(&cards, notations).map_err(|err| ShowdownError::InvalidCard {
+                location: Location::Hand(index),
+                card: err.card,
+            })?;
+        poker_hands.push((poker_hand, *hand));
+    }
+
+    Ok(poker_hands)
+}
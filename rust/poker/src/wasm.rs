@@ -0,0 +1,35 @@
+//! JavaScript bindings, built with
+//! `cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`
+//! followed by `wasm-bindgen --out-dir pkg target/wasm32-unknown-unknown/release/poker.wasm`.
+//!
+//! Hands are strings of five space separated cards in any of the [`Notations::ALL`]
+//! notations. Invalid input is reported as a JS `Error` instead of panicking.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{best_hands, showdown::checked_poker_hands, Notations};
+
+/// Category of a single hand, e.g. `"FullHouse"`.
+#[wasm_bindgen(js_name = handCategory)]
+pub fn hand_category(hand: &str) -> Result<String, JsError> {
+    let poker_hands = checked_poker_hands(&[hand], Notations::ALL)
+        .map_err(|err| JsError::new(&err.to_string()))?;
+
+    Ok(poker_hands
+        .first()
+        .map(|(poker_hand, _)| poker_hand.result.name().to_string())
+        .unwrap_or_default())
+}
+
+/// The winning hand(s), as given.
+#[wasm_bindgen(js_name = winningHands)]
+pub fn winning_hands(hands: Vec<String>) -> Result<Vec<String>, JsError> {
+    let hands = hands.iter().map(String::as_str).collect::<Vec<_>>();
+    let poker_hands = checked_poker_hands(&hands, Notations::ALL)
+        .map_err(|err| JsError::new(&err.to_string()))?;
+
+    Ok(best_hands(poker_hands)
+        .into_iter()
+        .map(str::to_string)
+        .collect())
+}
@@ -0,0 +1,101 @@
+use poker::{
+    self_play, Action, AlwaysCall, Card, HandType, HandView, PokerHand, Strategy, Threshold,
+};
+
+fn cards(cards: &str) -> Vec<Card> {
+    cards.split(' ').map(|c| c.parse().unwrap()).collect()
+}
+
+/// Folds whenever somebody bets, checks otherwise.
+struct Passive;
+
+impl Strategy for Passive {
+    fn act(&mut self, view: &HandView) -> Action {
+        if view.to_call > 0 {
+            Action::Fold
+        } else {
+            Action::Check
+        }
+    }
+}
+
+/// Bets every street, and records what it was shown.
+#[derive(Default)]
+struct Aggressive {
+    board_sizes: Vec<usize>,
+}
+
+impl Strategy for Aggressive {
+    fn act(&mut self, view: &HandView) -> Action {
+        assert_eq!(view.hole_cards.len(), 2);
+        self.board_sizes.push(view.board.len());
+        if view.legal_actions.contains(&Action::Bet) {
+            Action::Bet
+        } else {
+            Action::Call
+        }
+    }
+}
+
+#[test]
+fn test_best_of_seven_cards() {
+    let hand = PokerHand::best_of(&cards("2S 3S 4S 5S 6S AS AH")).unwrap();
+    assert_eq!(hand.result, HandType::StraightFlush(6));
+    assert_eq!(hand.canonical(), "2S 3S 4S 5S 6S");
+}
+
+#[test]
+fn test_best_of_too_few_cards() {
+    assert!(PokerHand::best_of(&cards("2S 3S 4S 5S")).is_none());
+}
+
+#[test]
+fn test_same_seed_same_report() {
+    let play = |seed| {
+        let mut a = AlwaysCall;
+        let mut b = Threshold {
+            min: HandType::OnePair(10, 0),
+        };
+        self_play(&mut [&mut a, &mut b], 200, seed)
+    };
+
+    assert_eq!(play(7), play(7));
+    assert_ne!(play(7), play(8));
+}
+
+#[test]
+fn test_report_accounts_for_every_hand_and_chip() {
+    let mut a = AlwaysCall;
+    let mut b = Threshold {
+        min: HandType::TwoPair(2, 2, 0),
+    };
+    let mut c = AlwaysCall;
+    let report = self_play(&mut [&mut a, &mut b, &mut c], 500, 42);
+
+    assert_eq!(report.hands, 500);
+    assert!((report.wins.iter().sum::<f64>() - 500.0).abs() < 1e-9);
+    assert_eq!(report.chips.iter().sum::<i64>(), 0);
+    let win_rates = (0..3).map(|p| report.win_rate(p)).sum::<f64>();
+    assert!((win_rates - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_betting_wins_uncontested_pots() {
+    let mut aggressive = Aggressive::default();
+    let mut passive = Passive;
+    let report = self_play(&mut [&mut aggressive, &mut passive], 100, 1);
+
+    // the passive player folds to the first bet, so it never wins a pot
+    assert_eq!(report.wins, vec![100.0, 0.0]);
+    assert_eq!(report.chips, vec![100, -100]);
+    assert!(aggressive.board_sizes.iter().all(|&size| size == 0));
+}
+
+#[test]
+fn test_calling_stations_see_every_street() {
+    let mut aggressive = Aggressive::default();
+    let mut caller = AlwaysCall;
+    self_play(&mut [&mut aggressive, &mut caller], 1, 3);
+
+    assert_eq!(aggressive.board_sizes, vec![0, 3, 4, 5]);
+}
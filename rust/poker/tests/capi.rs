@@ -0,0 +1,74 @@
+//! Builds the static library, compiles tests/capi/test.c against it and runs it.
+#![cfg(feature = "capi")]
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+#[test]
+fn test_header_is_up_to_date() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // generated by build.rs
+    let generated = fs::read_to_string(PathBuf::from(env!("OUT_DIR")).join("poker.h")).unwrap();
+    let committed = fs::read_to_string(manifest_dir.join("include/poker.h")).unwrap();
+    assert!(
+        generated == committed,
+        "include/poker.h is out of date, regenerate it with \
+         `cbindgen --config cbindgen.toml --output include/poker.h`"
+    );
+}
+
+#[test]
+fn test_c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // target/<profile>/deps/capi-<hash> => target/<profile>
+    let exe = env::current_exe().unwrap();
+    let profile_dir = exe.parent().unwrap().parent().unwrap();
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("capi_test");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    // test builds only produce the rlib, the C program needs libpoker.a
+    let mut cargo = Command::new(env!("CARGO"));
+    cargo.current_dir(&manifest_dir).args([
+        "rustc",
+        "--lib",
+        "--features",
+        "capi",
+        "--crate-type",
+        "staticlib",
+    ]);
+    if profile_dir.ends_with("release") {
+        cargo.arg("--release");
+    }
+    // the system libraries the standard library needs on this platform
+    cargo.args(["--", "--print", "native-static-libs"]);
+    let output = cargo.output().expect("failed to run cargo");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "building the static library failed\n{}",
+        stderr
+    );
+    let native_libs = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("note: native-static-libs: "))
+        .expect("cargo didn't print the native static libraries");
+
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join("tests/capi/test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(profile_dir.join("libpoker.a"))
+        .args(native_libs.split_whitespace())
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "compiling tests/capi/test.c failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
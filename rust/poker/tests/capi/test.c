@@ -0,0 +1,66 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "poker.h"
+
+static void test_evaluate(void) {
+    struct PokerHand *hand = poker_hand_parse("3S 3H 3D 8C 8S");
+    assert(hand != NULL);
+
+    struct PokerEvaluation evaluation;
+    assert(poker_hand_evaluate(hand, &evaluation) == 0);
+    assert(evaluation.category == 6);
+    const uint8_t kickers[5] = {3, 3, 3, 8, 8};
+    assert(memcmp(evaluation.kickers, kickers, 5) == 0);
+
+    poker_hand_free(hand);
+}
+
+static void test_ace_low_straight(void) {
+    struct PokerHand *hand = poker_hand_parse("4D AH 3S 2D 5C");
+    assert(hand != NULL);
+
+    struct PokerEvaluation evaluation;
+    assert(poker_hand_evaluate(hand, &evaluation) == 0);
+    assert(evaluation.category == 4);
+    const uint8_t kickers[5] = {5, 4, 3, 2, 1};
+    assert(memcmp(evaluation.kickers, kickers, 5) == 0);
+
+    poker_hand_free(hand);
+}
+
+static void test_compare(void) {
+    struct PokerHand *pair = poker_hand_parse("2S 4H 6S 4D JH");
+    struct PokerHand *high_card = poker_hand_parse("4S 5H 6C 8D KH");
+    struct PokerHand *other_high_card = poker_hand_parse("4D 5S 6D 8C KS");
+    assert(pair != NULL && high_card != NULL && other_high_card != NULL);
+
+    assert(poker_hand_compare(pair, high_card) == 1);
+    assert(poker_hand_compare(high_card, pair) == -1);
+    assert(poker_hand_compare(high_card, other_high_card) == 0);
+
+    poker_hand_free(pair);
+    poker_hand_free(high_card);
+    poker_hand_free(other_high_card);
+}
+
+static void test_invalid_input(void) {
+    assert(poker_hand_parse(NULL) == NULL);
+    assert(poker_hand_parse("3S 3H 3D 8C") == NULL);
+    assert(poker_hand_parse("3S 3H 3D 8C XX") == NULL);
+    assert(poker_hand_parse("3S 3S 3D 8C 8S") == NULL);
+
+    struct PokerEvaluation evaluation;
+    assert(poker_hand_evaluate(NULL, &evaluation) == -1);
+    poker_hand_free(NULL);
+}
+
+int main(void) {
+    test_evaluate();
+    test_ace_low_straight();
+    test_compare();
+    test_invalid_input();
+    printf("ok\n");
+    return 0;
+}
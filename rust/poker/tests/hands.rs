@@ -30,3 +30,9 @@ fn test_straight() {
     let hand = PokerHand::new(&["QS", "10H", "JH", "9C", "8C"]);
     assert_eq!(hand.result, HandType::Straight(12))
 }
+
+#[test]
+fn test_hand_type_name() {
+    let hand = PokerHand::new(&["3S", "3H", "3D", "8C", "8S"]);
+    assert_eq!(hand.result.name(), "FullHouse")
+}
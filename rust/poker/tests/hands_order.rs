@@ -24,8 +24,8 @@ fn test_straight_flush_vs_straight() {
     assert!(better_hand > hand)
 }
 
- //  left: `{"AS AC KS KC 6S"}`,
- // right: `{"2H 2D 2C 8H 5H"}`', tests/poker.rs:18:5
+// two pair "AS AC KS KC 6S" must lose against the triplet "2H 2D 2C 8H 5H",
+// see tests/properties.rs for the ordering over all hand types
 #[test]
 fn test_two_pair_three_of_a_kind() {
     let better_hand = HandType::ThreeOfAKind(2, 8);
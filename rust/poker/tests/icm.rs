@@ -0,0 +1,169 @@
+use poker::{
+    icm_equity, settle_all_in, IcmError, Location, SettleError, Settlement, ShowdownError,
+};
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_three_players_three_payouts() {
+    assert_close(
+        &icm_equity(&[5000, 3000, 2000], &[50.0, 30.0, 20.0]).unwrap(),
+        &[38.392857142857, 32.75, 28.857142857142854],
+    )
+}
+
+#[test]
+fn test_fewer_payouts_than_players() {
+    assert_close(
+        &icm_equity(&[5000, 3000, 2000], &[70.0, 30.0]).unwrap(),
+        &[45.178571428571, 32.25, 22.571428571429],
+    );
+    assert_close(
+        &icm_equity(&[1000, 2000, 3000, 4000], &[50.0, 30.0, 20.0]).unwrap(),
+        &[
+            13.321428571428571,
+            23.587301587301592,
+            29.488095238095237,
+            33.603174603175,
+        ],
+    );
+}
+
+#[test]
+fn test_equal_stacks_share_equally() {
+    assert_close(
+        &icm_equity(&[100, 100, 100, 100], &[60.0, 40.0]).unwrap(),
+        &[25.0, 25.0, 25.0, 25.0],
+    )
+}
+
+#[test]
+fn test_single_player_takes_first_prize() {
+    assert_close(
+        &icm_equity(&[1500, 0, 0], &[70.0, 20.0, 10.0]).unwrap(),
+        &[70.0, 15.0, 15.0],
+    )
+}
+
+#[test]
+fn test_many_players() {
+    let stacks = (1..=100).collect::<Vec<u64>>();
+    let equity = icm_equity(&stacks, &[50.0, 30.0, 20.0]).unwrap();
+
+    assert!((equity.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    assert!(equity.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_all_in_winner_takes_the_pot() {
+    let settlement = settle_all_in(
+        &[1000, 1000],
+        &[Some("2S 8H 2D 8D 3H"), Some("4S 5H 4C 8S 5D")],
+        &[70.0, 30.0],
+    )
+    .unwrap();
+    assert_eq!(settlement.stacks, vec![2000, 0]);
+    assert_close(&settlement.equity, &[70.0, 30.0]);
+}
+
+#[test]
+fn test_all_in_short_stack_wins_main_pot() {
+    // player 2 has the best hand but can only win the main pot of 3 * 500
+    let settlement = settle_all_in(
+        &[2000, 1500, 500],
+        &[
+            Some("4S 5H 6C 8D KH"),
+            Some("2S 4H 6S 4D JH"),
+            Some("3S 3H 3D 8C 8S"),
+        ],
+        &[50.0, 30.0, 20.0],
+    )
+    .unwrap();
+    assert_eq!(settlement.stacks, vec![500, 2000, 1500]);
+}
+
+#[test]
+fn test_all_in_tie_splits_the_pot() {
+    let settlement = settle_all_in(
+        &[1001, 1000, 3000],
+        &[Some("3S 4S 5D 6H JH"), Some("3H 4H 5C 6C JD"), None],
+        &[50.0, 30.0, 20.0],
+    )
+    .unwrap();
+    // the uncalled chip goes back to player 0
+    assert_eq!(settlement.stacks, vec![1001, 1000, 3000]);
+}
+
+#[test]
+fn test_all_in_odd_chip_goes_to_first_seat() {
+    let settlement = settle_all_in(
+        &[501, 501, 501],
+        &[
+            Some("3S 4S 5D 6H JH"),
+            Some("3H 4H 5C 6C JD"),
+            Some("2S 4C 7S 9H 10H"),
+        ],
+        &[100.0],
+    )
+    .unwrap();
+    assert_eq!(
+        settlement,
+        Settlement {
+            stacks: vec![752, 751, 0],
+            equity: vec![100.0 * 752.0 / 1503.0, 100.0 * 751.0 / 1503.0, 0.0],
+        }
+    );
+}
+
+#[test]
+fn test_more_than_128_players() {
+    let stacks = (1..=200).collect::<Vec<u64>>();
+    let equity = icm_equity(&stacks, &[50.0, 30.0, 20.0]).unwrap();
+
+    assert!((equity.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    assert!(equity.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_too_many_paid_places() {
+    let stacks = vec![100; 200];
+    assert_eq!(
+        icm_equity(&stacks, &[1.0; 20]),
+        Err(IcmError::TooManyPlaces {
+            players: 200,
+            places: 20
+        })
+    );
+}
+
+#[test]
+fn test_all_in_rejects_invalid_hands() {
+    assert_eq!(
+        settle_all_in(
+            &[1000, 1000, 1000],
+            &[Some("2S 8H 2D 8D 3H"), None, Some("XX 5H 4C 8S 5D")],
+            &[70.0, 30.0],
+        ),
+        Err(SettleError::Showdown(ShowdownError::InvalidCard {
+            location: Location::Hand(2),
+            card: "XX".to_string(),
+        }))
+    );
+    assert_eq!(
+        settle_all_in(
+            &[1000, 1000, 1000],
+            &[None, Some("2S 8H 2D 8D 3H"), Some("4S 5H 4C 8S 8H")],
+            &[70.0, 30.0],
+        ),
+        Err(SettleError::Showdown(ShowdownError::DuplicateCard {
+            card: "8H".to_string(),
+            first: Location::Hand(1),
+            second: Location::Hand(2),
+        }))
+    );
+}
@@ -0,0 +1,94 @@
+use poker::{
+    checked_winning_hands_with, validate_showdown_with, Card, HandType, Location, Notations,
+    PokerHand, ShowdownError,
+};
+
+fn canonical(card: &str, notations: Notations) -> Option<String> {
+    Card::parse_with(card, notations)
+        .ok()
+        .map(|card| card.canonical())
+}
+
+#[test]
+fn test_standard_notation() {
+    assert_eq!(canonical("10H", Notations::STANDARD), Some("10H".into()));
+    assert_eq!(canonical("AS", Notations::STANDARD), Some("AS".into()));
+    assert_eq!(canonical("1S", Notations::STANDARD), Some("AS".into()));
+}
+
+#[test]
+fn test_standard_notation_rejects_alternatives() {
+    for card in ["Ts", "TS", "t♠", "A♥", "ah", "10h"] {
+        assert_eq!(canonical(card, Notations::STANDARD), None, "{}", card);
+    }
+}
+
+#[test]
+fn test_ten_as_t() {
+    let notations = Notations {
+        ten_as_t: true,
+        ..Notations::STANDARD
+    };
+    assert_eq!(canonical("TS", notations), Some("10S".into()));
+    assert_eq!(canonical("Ts", notations), None);
+}
+
+#[test]
+fn test_unicode_suits() {
+    let notations = Notations {
+        unicode_suits: true,
+        ..Notations::STANDARD
+    };
+    assert_eq!(canonical("A♥", notations), Some("AH".into()));
+    assert_eq!(canonical("10♠️", notations), Some("10S".into()));
+    assert_eq!(canonical("K♧", notations), Some("KC".into()));
+    assert_eq!(canonical("2♦", notations), Some("2D".into()));
+}
+
+#[test]
+fn test_case_insensitive() {
+    let notations = Notations {
+        case_insensitive: true,
+        ..Notations::STANDARD
+    };
+    assert_eq!(canonical("ah", notations), Some("AH".into()));
+    assert_eq!(canonical("qd", notations), Some("QD".into()));
+    assert_eq!(canonical("t♠", notations), None);
+}
+
+#[test]
+fn test_all_notations() {
+    for (card, expected) in [("Ts", "10S"), ("t♠", "10S"), ("A♥", "AH"), ("ah", "AH")] {
+        assert_eq!(canonical(card, Notations::ALL), Some(expected.into()));
+    }
+    assert_eq!(canonical("Xs", Notations::ALL), None);
+    assert_eq!(canonical("11♠", Notations::ALL), None);
+}
+
+#[test]
+fn test_parse_hand_canonical() {
+    let hand = PokerHand::parse(&["Ts", "j♥", "Q♥", "k♥", "A♥"], Notations::ALL).unwrap();
+    assert_eq!(hand.canonical(), "10S JH QH KH AH");
+    assert_eq!(hand.result, HandType::Straight(14));
+}
+
+#[test]
+fn test_duplicates_across_notations() {
+    assert_eq!(
+        validate_showdown_with(&["As 5S 7H 8D JC", "A♠ 4C 7S 9H Th"], &[], Notations::ALL),
+        Err(ShowdownError::DuplicateCard {
+            card: "A♠".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(1),
+        })
+    )
+}
+
+#[test]
+fn test_winning_hands_with_notations() {
+    let input = &["4s 5♠ 7h 8d jc", "2S 4C 7S 9H TH"];
+    assert_eq!(
+        checked_winning_hands_with(input, Notations::ALL),
+        Ok(vec!["4s 5♠ 7h 8d jc"])
+    )
+}
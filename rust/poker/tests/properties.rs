@@ -0,0 +1,114 @@
+use poker::{winning_hands, HandType, Notations, PokerHand};
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+use std::cmp::Ordering;
+
+const RANKS: [&str; 13] = [
+    "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A",
+];
+const SUITS: [&str; 4] = ["C", "D", "H", "S"];
+
+fn deck() -> Vec<String> {
+    SUITS
+        .iter()
+        .flat_map(|suit| RANKS.iter().map(move |rank| format!("{}{}", rank, suit)))
+        .collect()
+}
+
+/// Five distinct cards of a standard deck, in random order.
+fn five_cards() -> impl Strategy<Value = Vec<String>> {
+    subsequence(deck(), 5).prop_shuffle()
+}
+
+/// Five cards and the same five cards in another order.
+fn permuted_cards() -> impl Strategy<Value = (Vec<String>, Vec<String>)> {
+    five_cards().prop_flat_map(|cards| (Just(cards.clone()), Just(cards).prop_shuffle()))
+}
+
+fn hand_type(cards: &[String]) -> HandType {
+    let cards = cards.iter().map(String::as_str).collect::<Vec<_>>();
+    PokerHand::new(&cards).result
+}
+
+fn hand_types() -> impl Strategy<Value = HandType> {
+    five_cards().prop_map(|cards| hand_type(&cards))
+}
+
+proptest! {
+    #[test]
+    fn test_hand_type_order_is_total(a in hand_types(), b in hand_types()) {
+        let ab = a.partial_cmp(&b);
+        let ba = b.partial_cmp(&a);
+        prop_assert!(ab.is_some());
+        prop_assert_eq!(ab, ba.map(Ordering::reverse));
+        prop_assert_eq!(ab == Some(Ordering::Equal), a == b);
+    }
+
+    #[test]
+    fn test_hand_type_order_is_transitive(a in hand_types(), b in hand_types(), c in hand_types()) {
+        let mut sorted = [a, b, c];
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        prop_assert!(sorted[0] <= sorted[1]);
+        prop_assert!(sorted[1] <= sorted[2]);
+        prop_assert!(sorted[0] <= sorted[2]);
+    }
+
+    #[test]
+    fn test_permuting_cards_keeps_hand_type((cards, shuffled) in permuted_cards()) {
+        prop_assert_eq!(hand_type(&cards), hand_type(&shuffled));
+    }
+
+    #[test]
+    fn test_permuting_cards_keeps_winners(
+        (a, a_shuffled) in permuted_cards(),
+        (b, b_shuffled) in permuted_cards(),
+    ) {
+        let (a, a_shuffled) = (a.join(" "), a_shuffled.join(" "));
+        let (b, b_shuffled) = (b.join(" "), b_shuffled.join(" "));
+
+        let winners = winning_hands(&[&a, &b]);
+        let shuffled_winners = winning_hands(&[&b_shuffled, &a_shuffled]);
+        prop_assert_eq!(winners.contains(&a.as_str()), shuffled_winners.contains(&a_shuffled.as_str()));
+        prop_assert_eq!(winners.contains(&b.as_str()), shuffled_winners.contains(&b_shuffled.as_str()));
+    }
+}
+
+/// Count the hand categories of all 2,598,960 five-card hands.
+#[test]
+fn test_category_census() {
+    let deck = deck();
+
+    // indexed like `HandType`: straight flush first
+    let mut census = [0_u32; 9];
+    let mut hand = Vec::with_capacity(5);
+    for a in 0..deck.len() {
+        for b in a + 1..deck.len() {
+            for c in b + 1..deck.len() {
+                for d in c + 1..deck.len() {
+                    for e in d + 1..deck.len() {
+                        hand.clear();
+                        hand.extend([a, b, c, d, e].iter().map(|&i| deck[i].as_str()));
+                        let poker_hand = PokerHand::parse(&hand, Notations::STANDARD).unwrap();
+                        let index = match poker_hand.result {
+                            HandType::StraightFlush(_) => 0,
+                            HandType::FourOfAKind(_, _) => 1,
+                            HandType::FullHouse(_, _) => 2,
+                            HandType::Flush(_) => 3,
+                            HandType::Straight(_) => 4,
+                            HandType::ThreeOfAKind(_, _) => 5,
+                            HandType::TwoPair(_, _, _) => 6,
+                            HandType::OnePair(_, _) => 7,
+                            HandType::HighCard(_) => 8,
+                        };
+                        census[index] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        census,
+        [40, 624, 3_744, 5_108, 10_200, 54_912, 123_552, 1_098_240, 1_302_540]
+    );
+}
@@ -0,0 +1,116 @@
+use poker::{rank_hands, Decider, HandType, Location, RankedHand, ShowdownError};
+
+#[test]
+fn test_single_hand() {
+    assert_eq!(
+        rank_hands(&["4S 5S 7H 8D JC"]).unwrap(),
+        vec![RankedHand {
+            hand: "4S 5S 7H 8D JC",
+            hand_type: HandType::HighCard(11),
+            position: 1,
+            decided_by: None,
+        }]
+    )
+}
+
+#[test]
+fn test_every_hand_is_ranked() {
+    let ranking = rank_hands(&["4S 5H 6C 8D KH", "2S 4H 6S 4D JH", "3S 3H 3D 8C 8S"]).unwrap();
+    let hands = ranking.iter().map(|r| r.hand).collect::<Vec<_>>();
+    assert_eq!(
+        hands,
+        ["3S 3H 3D 8C 8S", "2S 4H 6S 4D JH", "4S 5H 6C 8D KH"]
+    );
+
+    let positions = ranking.iter().map(|r| r.position).collect::<Vec<_>>();
+    assert_eq!(positions, [1, 2, 3]);
+
+    assert_eq!(ranking[0].hand_type, HandType::FullHouse(3, 8));
+    assert_eq!(ranking[0].decided_by, Some(Decider::Category));
+    assert_eq!(ranking[1].decided_by, Some(Decider::Category));
+    assert_eq!(ranking[2].decided_by, None);
+}
+
+#[test]
+fn test_ties_share_a_position() {
+    let ranking = rank_hands(&[
+        "4D 5S 6S 8D 3C",
+        "3S 4S 5D 6H JH",
+        "2S 4C 7S 9H 10H",
+        "3H 4H 5C 6C JD",
+    ])
+    .unwrap();
+    let hands = ranking.iter().map(|r| r.hand).collect::<Vec<_>>();
+    assert_eq!(
+        hands,
+        [
+            "3S 4S 5D 6H JH",
+            "3H 4H 5C 6C JD",
+            "2S 4C 7S 9H 10H",
+            "4D 5S 6S 8D 3C"
+        ]
+    );
+
+    let positions = ranking.iter().map(|r| r.position).collect::<Vec<_>>();
+    assert_eq!(positions, [1, 1, 3, 4]);
+    assert_eq!(ranking[0].decided_by, Some(Decider::Tie));
+    // jack high over ten high: high cards only have kickers
+    assert_eq!(ranking[1].decided_by, Some(Decider::Kicker));
+}
+
+#[test]
+fn test_rank_within_category() {
+    let ranking = rank_hands(&["4S 2H 6S 2D JH", "2S 4H 6C 4D JD"]).unwrap();
+    assert_eq!(ranking[0].hand, "2S 4H 6C 4D JD");
+    assert_eq!(ranking[0].decided_by, Some(Decider::Rank));
+}
+
+#[test]
+fn test_kicker_of_a_pair_decides() {
+    let ranking = rank_hands(&["10D 10C QH 4D 2H", "10S 10H KD 4C 2S"]).unwrap();
+    assert_eq!(ranking[0].hand, "10S 10H KD 4C 2S");
+    assert_eq!(ranking[0].decided_by, Some(Decider::Kicker));
+}
+
+#[test]
+fn test_kicker_of_a_flush_decides() {
+    let ranking = rank_hands(&["AD 8D 7D 5D 3D", "AS 9S 7S 5S 3S"]).unwrap();
+    assert_eq!(ranking[0].hand, "AS 9S 7S 5S 3S");
+    assert_eq!(ranking[0].decided_by, Some(Decider::Kicker));
+}
+
+#[test]
+fn test_rank_of_a_straight_decides() {
+    let ranking = rank_hands(&["2S 3H 4D 5C 6S", "3D 4C 5H 6D 7H"]).unwrap();
+    assert_eq!(ranking[0].hand, "3D 4C 5H 6D 7H");
+    assert_eq!(ranking[0].decided_by, Some(Decider::Rank));
+}
+
+#[test]
+fn test_kicker_decides() {
+    let ranking = rank_hands(&["3S 5H 6S 8D 7H", "2S 5D 6D 8C 7S"]).unwrap();
+    assert_eq!(ranking[0].hand, "3S 5H 6S 8D 7H");
+    assert_eq!(ranking[0].hand_type, ranking[1].hand_type);
+    assert_eq!(ranking[0].decided_by, Some(Decider::Kicker));
+    assert_eq!(ranking[1].position, 2);
+}
+
+#[test]
+fn test_invalid_hands_are_rejected() {
+    assert_eq!(
+        rank_hands(&["4S 5S 7H 8D JC", "XX 5H 6C 8H KH"]),
+        Err(ShowdownError::InvalidCard {
+            location: Location::Hand(1),
+            card: "XX".to_string(),
+        })
+    );
+    assert_eq!(
+        rank_hands(&["4S 5S 7H 8D JC", "4S 5H 6C 8H KH"]),
+        Err(ShowdownError::DuplicateCard {
+            card: "4S".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(1),
+        })
+    );
+    assert_eq!(rank_hands(&[]), Err(ShowdownError::NoHands));
+}
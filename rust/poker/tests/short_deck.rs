@@ -0,0 +1,154 @@
+use poker::{winning_hands_with_rules, Card, HandType, Location, PokerHand, Rules, ShowdownError};
+use std::cmp::Ordering;
+
+fn short_deck_hand(cards: &[&str]) -> HandType {
+    PokerHand::new(cards).with_rules(Rules::ShortDeck).result
+}
+
+#[test]
+fn test_flush_beats_full_house() {
+    let flush = HandType::Flush(11);
+    let full_house = HandType::FullHouse(14, 13);
+
+    assert_eq!(
+        flush.cmp_with(&full_house, Rules::ShortDeck),
+        Ordering::Greater
+    );
+    assert_eq!(flush.cmp_with(&full_house, Rules::Standard), Ordering::Less);
+}
+
+#[test]
+fn test_other_categories_keep_their_order() {
+    let ordered = [
+        HandType::StraightFlush(9),
+        HandType::FourOfAKind(6, 7),
+        HandType::Flush(11),
+        HandType::FullHouse(14, 13),
+        HandType::Straight(14),
+        HandType::ThreeOfAKind(14, 13),
+        HandType::TwoPair(14, 13, 12),
+        HandType::OnePair(14, 13),
+        HandType::HighCard(14),
+    ];
+
+    for (i, better) in ordered.iter().enumerate() {
+        for worse in &ordered[i + 1..] {
+            assert_eq!(better.cmp_with(worse, Rules::ShortDeck), Ordering::Greater);
+            assert_eq!(worse.cmp_with(better, Rules::ShortDeck), Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn test_same_category_compares_ranks() {
+    assert_eq!(
+        HandType::Flush(13).cmp_with(&HandType::Flush(14), Rules::ShortDeck),
+        Ordering::Less
+    );
+    assert_eq!(
+        HandType::FullHouse(9, 6).cmp_with(&HandType::FullHouse(9, 6), Rules::ShortDeck),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_ace_six_straight() {
+    assert_eq!(
+        short_deck_hand(&["AS", "6H", "7D", "8C", "9S"]),
+        HandType::Straight(9)
+    );
+    assert_eq!(
+        PokerHand::new(&["AS", "6H", "7D", "8C", "9S"]).result,
+        HandType::HighCard(14)
+    );
+}
+
+#[test]
+fn test_ace_six_straight_flush() {
+    assert_eq!(
+        short_deck_hand(&["AH", "6H", "7H", "8H", "9H"]),
+        HandType::StraightFlush(9)
+    );
+}
+
+#[test]
+fn test_ace_high_straight_is_unchanged() {
+    assert_eq!(
+        short_deck_hand(&["AS", "KH", "QD", "JC", "10S"]),
+        HandType::Straight(14)
+    );
+}
+
+#[test]
+fn test_ace_six_straight_is_the_lowest_straight() {
+    let input = &["AS 6H 7D 8C 9S", "6S 7H 8D 9C 10S"];
+    assert_eq!(
+        winning_hands_with_rules(input, Rules::ShortDeck),
+        Ok(vec!["6S 7H 8D 9C 10S"])
+    )
+}
+
+#[test]
+fn test_flush_wins_showdown_against_full_house() {
+    let input = &["KH 7H 9H JH 6H", "AS AC AD 8C 8S"];
+    assert_eq!(
+        winning_hands_with_rules(input, Rules::ShortDeck),
+        Ok(vec!["KH 7H 9H JH 6H"])
+    );
+    assert_eq!(
+        winning_hands_with_rules(input, Rules::Standard),
+        Ok(vec!["AS AC AD 8C 8S"])
+    );
+}
+
+#[test]
+fn test_short_deck_cards() {
+    let allowed = |card: &str| Rules::ShortDeck.allows(&card.parse::<Card>().unwrap());
+    assert!(allowed("AS"));
+    assert!(allowed("6H"));
+    assert!(!allowed("5D"));
+    assert!(!allowed("2C"));
+    assert!(Rules::Standard.allows(&"2C".parse::<Card>().unwrap()));
+}
+
+#[test]
+fn test_short_deck_showdown_rejects_removed_cards() {
+    let input = &["KH 7H 9H JH 6H", "AS AC AD 8C 5S"];
+    assert_eq!(
+        winning_hands_with_rules(input, Rules::ShortDeck),
+        Err(ShowdownError::InvalidCard {
+            location: Location::Hand(1),
+            card: "5S".to_string(),
+        })
+    );
+    assert_eq!(
+        winning_hands_with_rules(input, Rules::Standard),
+        Ok(vec!["KH 7H 9H JH 6H"])
+    );
+}
+
+#[test]
+fn test_showdown_with_rules_is_validated() {
+    assert_eq!(
+        winning_hands_with_rules(&[], Rules::ShortDeck),
+        Err(ShowdownError::NoHands)
+    );
+    assert!(matches!(
+        winning_hands_with_rules(&["AS 6H 7D 8C 9S", "AS KH QD JC 10S"], Rules::ShortDeck),
+        Err(ShowdownError::DuplicateCard { .. })
+    ));
+}
+
+#[test]
+fn test_hands_under_different_rules_are_not_ordered() {
+    let flush = PokerHand::new(&["KH", "7H", "9H", "JH", "6H"]).with_rules(Rules::ShortDeck);
+    let full_house = PokerHand::new(&["AS", "AC", "AD", "8C", "8S"]);
+
+    assert_eq!(flush.partial_cmp(&full_house), None);
+    assert_eq!(full_house.partial_cmp(&flush), None);
+    assert!(flush != full_house);
+
+    let full_house = full_house.with_rules(Rules::ShortDeck);
+    assert_eq!(flush.partial_cmp(&full_house), Some(Ordering::Greater));
+    assert_eq!(full_house.partial_cmp(&flush), Some(Ordering::Less));
+}
@@ -0,0 +1,168 @@
+use poker::{checked_winning_hands, validate_showdown, winning_hands, Location, ShowdownError};
+
+#[test]
+fn test_valid_showdown() {
+    assert_eq!(
+        validate_showdown(&["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H"], &[]),
+        Ok(())
+    )
+}
+
+#[test]
+fn test_valid_showdown_with_board() {
+    assert_eq!(
+        validate_showdown(&["AS AC", "KH QH"], &["2D", "3D", "4C", "JH", "10H"]),
+        Ok(())
+    )
+}
+
+#[test]
+fn test_no_hands() {
+    assert_eq!(validate_showdown(&[], &[]), Err(ShowdownError::NoHands))
+}
+
+#[test]
+fn test_duplicate_card_between_hands() {
+    assert_eq!(
+        validate_showdown(
+            &["4S 5S 7H 8D JC", "2S 4C 7S 9H 10H", "3S 4S 5D 6H QH"],
+            &[]
+        ),
+        Err(ShowdownError::DuplicateCard {
+            card: "4S".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(2),
+        })
+    )
+}
+
+#[test]
+fn test_duplicate_card_within_hand() {
+    assert_eq!(
+        validate_showdown(&["4S 5S 7H 8D 4S"], &[]),
+        Err(ShowdownError::DuplicateCard {
+            card: "4S".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(0),
+        })
+    )
+}
+
+#[test]
+fn test_ace_notations_are_the_same_card() {
+    assert_eq!(
+        validate_showdown(&["AS 5S 7H 8D JC", "1S 4C 7S 9H 10H"], &[]),
+        Err(ShowdownError::DuplicateCard {
+            card: "1S".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(1),
+        })
+    )
+}
+
+#[test]
+fn test_duplicate_card_with_board() {
+    assert_eq!(
+        validate_showdown(&["AS AC", "KH JH"], &["2D", "3D", "4C", "JH", "10H"]),
+        Err(ShowdownError::DuplicateCard {
+            card: "JH".to_string(),
+            first: Location::Board,
+            second: Location::Hand(1),
+        })
+    )
+}
+
+#[test]
+fn test_invalid_cards() {
+    for card in ["11S", "0H", "4X", "ZS", "10", "", "02H"] {
+        let hand = format!("{} 5S 7H 8D JC", card);
+        assert_eq!(
+            validate_showdown(&[&hand], &[]),
+            Err(ShowdownError::InvalidCard {
+                location: Location::Hand(0),
+                card: card.to_string(),
+            })
+        )
+    }
+}
+
+#[test]
+fn test_hands_of_different_sizes() {
+    assert_eq!(
+        validate_showdown(&["4S 5S 7H 8D JC", "2S 4C 7S 9H"], &[]),
+        Err(ShowdownError::WrongCardCount {
+            location: Location::Hand(1),
+            expected: 5,
+            found: 4,
+        })
+    )
+}
+
+#[test]
+fn test_too_few_cards_with_board() {
+    assert_eq!(
+        validate_showdown(&["AS", "KH"], &["2D", "3D", "4C"]),
+        Err(ShowdownError::WrongCardCount {
+            location: Location::Hand(0),
+            expected: 2,
+            found: 1,
+        })
+    )
+}
+
+#[test]
+fn test_board_too_large() {
+    assert_eq!(
+        validate_showdown(&["AS AC"], &["2D", "3D", "4C", "JH", "10H", "9H"]),
+        Err(ShowdownError::WrongCardCount {
+            location: Location::Board,
+            expected: 5,
+            found: 6,
+        })
+    )
+}
+
+#[test]
+fn test_checked_winning_hands() {
+    assert_eq!(
+        checked_winning_hands(&["4S 5S 7H 8D JC", "2S 4C 7S 9H QH"]),
+        Ok(vec!["2S 4C 7S 9H QH"])
+    )
+}
+
+#[test]
+fn test_checked_winning_hands_rejects_shared_cards() {
+    let input = &["3S 4S 5D 6H JH", "3S 4S 5D 6H JH"];
+    assert_eq!(
+        checked_winning_hands(input),
+        Err(ShowdownError::DuplicateCard {
+            card: "3S".to_string(),
+            first: Location::Hand(0),
+            second: Location::Hand(1),
+        })
+    )
+}
+
+#[test]
+fn test_checked_winning_hands_needs_five_cards() {
+    assert_eq!(
+        checked_winning_hands(&["4S 5S 7H 8D JC 2D 3D"]),
+        Err(ShowdownError::WrongCardCount {
+            location: Location::Hand(0),
+            expected: 5,
+            found: 7,
+        })
+    )
+}
+
+#[test]
+fn test_winning_hands_without_hands() {
+    assert!(winning_hands(&[]).is_empty());
+    assert_eq!(checked_winning_hands(&[]), Err(ShowdownError::NoHands));
+}
+
+#[test]
+#[should_panic(expected = "hand 1 holds 4 cards, expected 5")]
+fn test_winning_hands_with_a_short_hand() {
+    winning_hands(&["4S 5S 7H 8D JC", "2S 4C 7S 9H"]);
+}
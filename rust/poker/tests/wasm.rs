@@ -0,0 +1,34 @@
+//! Run with `wasm-pack test --node -- --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use poker::wasm::{hand_category, winning_hands};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_hand_category() {
+    assert_eq!(hand_category("3S 3H 3D 8C 8S").unwrap(), "FullHouse");
+    assert_eq!(hand_category("Ts j♥ Q♥ k♥ A♥").unwrap(), "Straight");
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_hand_is_an_error() {
+    assert!(hand_category("3S 3H 3D 8C").is_err());
+    assert!(hand_category("3S 3H 3D 8C XX").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_winning_hands() {
+    let hands = vec!["4S 5H 6C 8D KH".to_string(), "2S 4H 6S 4D JH".to_string()];
+    assert_eq!(winning_hands(hands).unwrap(), vec!["2S 4H 6S 4D JH"]);
+}
+
+#[wasm_bindgen_test]
+fn test_winning_hands_rejects_shared_cards() {
+    let hands = vec!["4S 5H 6C 8D KH".to_string(), "4S 4H 6S 4D JH".to_string()];
+    assert!(winning_hands(hands).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_no_hands_is_an_error() {
+    assert!(winning_hands(Vec::new()).is_err());
+}